@@ -169,6 +169,8 @@ fn write_cli() {
 		"-c", "--prefix-color",
 		"-e", "--exit",
 		"-p", "--prefix",
+		"--repeat",
+		"--tee",
 	]);
 	builder.save(out_path("argyle-msg.rs"));
 }
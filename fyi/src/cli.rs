@@ -15,11 +15,17 @@ use fyi_msg::{
 	Msg,
 	MsgKind,
 };
-use std::num::NonZeroUsize;
+use std::{
+	num::NonZeroUsize,
+	path::{
+		Path,
+		PathBuf,
+	},
+};
 
 
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 /// # Message Settings.
 ///
 /// Most of these settings will get embedded into the `Msg` itself, but there
@@ -30,6 +36,12 @@ pub(super) struct Settings {
 
 	/// # Exit.
 	exit: i32,
+
+	/// # Repeat Count.
+	repeat: NonZeroUsize,
+
+	/// # Tee Path.
+	tee: Option<PathBuf>,
 }
 
 impl Settings {
@@ -46,23 +58,29 @@ impl Settings {
 	const FLAG_YES: u8 =       0b1000;
 
 	/// # Exit Code.
-	pub(super) const fn exit(self) -> Result<(), FyiError> {
+	pub(super) const fn exit(&self) -> Result<(), FyiError> {
 		if self.exit == 0 { Ok(()) }
 		else { Err(FyiError::Passthrough(self.exit)) }
 	}
 
 	/// # Stderr?
-	pub(super) const fn stderr(self) -> bool {
+	pub(super) const fn stderr(&self) -> bool {
 		Self::FLAG_STDERR == self.flags & Self::FLAG_STDERR
 	}
 
 	/// # Default Yes?
-	pub(super) const fn yes(self) -> bool {
+	pub(super) const fn yes(&self) -> bool {
 		Self::FLAG_YES == self.flags & Self::FLAG_YES
 	}
 
+	/// # Repeat Count.
+	pub(super) const fn repeat(&self) -> NonZeroUsize { self.repeat }
+
+	/// # Tee Path.
+	pub(super) fn tee(&self) -> Option<&Path> { self.tee.as_deref() }
+
 	/// # Convert to `Msg` Flags.
-	const fn msg_flags(self) -> u8 {
+	const fn msg_flags(&self) -> u8 {
 		let mut flags: u8 = fyi_msg::FLAG_NEWLINE;
 		if Self::FLAG_INDENT == self.flags & Self::FLAG_INDENT {
 			flags |= fyi_msg::FLAG_INDENT;
@@ -75,7 +93,7 @@ impl Settings {
 
 	/// # New.
 	const fn new() -> Self {
-		Self { flags: 0, exit: 0 }
+		Self { flags: 0, exit: 0, repeat: NonZeroUsize::MIN, tee: None }
 	}
 
 	/// # Set Indent.
@@ -135,11 +153,9 @@ pub(super) fn parse_blank() -> Result<(), FyiError> {
 	}
 
 	// Print it!
-	let lines = "\n".repeat(count.get());
-	if stderr { eprint!("{lines}"); }
-	else { print!("{lines}"); }
-
-	Ok(())
+	let lines = Msg::blank_lines(count.get());
+	if stderr { FyiError::from_print(lines.try_eprint()) }
+	else { FyiError::from_print(lines.try_print()) }
 }
 
 /// # Parse Message.
@@ -165,6 +181,10 @@ pub(super) fn parse_msg(kind: MsgKind) -> Result<(Msg, Settings), FyiError> {
 			Argument::KeyWithValue("-p" | "--prefix", s) => { prefix = s; },
 			Argument::KeyWithValue("-e" | "--exit", s) =>
 				if let Some(s) = i32::btoi(s.trim().as_bytes()) { flags.exit = s; },
+			Argument::KeyWithValue("--repeat", s) =>
+				if let Some(s) = NonZeroUsize::btou(s.trim().as_bytes()) { flags.repeat = s; },
+			Argument::KeyWithValue("--tee", s) =>
+				if ! s.trim().is_empty() { flags.tee = Some(PathBuf::from(s)); },
 
 			Argument::Other(s) =>
 				if msg.is_none() { msg.replace(s); }
@@ -3,7 +3,10 @@
 */
 
 use fyi_msg::MsgKind;
-use std::fmt;
+use std::{
+	fmt,
+	io,
+};
 
 
 
@@ -53,6 +56,19 @@ impl FyiError {
 		}
 	}
 
+	/// # From Print Result.
+	///
+	/// Output piped to a program like `head` may have its pipe closed early.
+	/// That isn't worth complaining about, so `BrokenPipe` errors are
+	/// converted into a silent, successful exit. Other write errors are
+	/// ignored, as they always have been.
+	pub(super) fn from_print(res: io::Result<()>) -> Result<(), Self> {
+		match res {
+			Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Err(Self::Passthrough(0)),
+			_ => Ok(()),
+		}
+	}
+
 	/// # Exit Code.
 	pub(super) const fn exit_code(self) -> i32 {
 		match self {
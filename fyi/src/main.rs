@@ -63,6 +63,11 @@ use fyi_msg::{
 	Msg,
 	MsgKind,
 };
+use std::{
+	io::Write,
+	num::NonZeroUsize,
+	path::Path,
+};
 
 
 
@@ -72,8 +77,16 @@ fn main() {
 	if let Err(e) = main__() {
 		match e {
 			FyiError::Passthrough(_) => {},
-			FyiError::PrintHelp(x) => return helper(x),
-			FyiError::PrintVersion => { println!("{}", FyiError::PrintVersion); },
+			// If the pipe was closed, there's nothing to be done.
+			FyiError::PrintHelp(x) => {
+				let _res = helper(x);
+				return;
+			},
+			FyiError::PrintVersion => {
+				let _res = Msg::plain(FyiError::PrintVersion.as_str())
+					.with_newline(true)
+					.try_print();
+			},
 			_ => { Msg::error(e.to_string()).eprint(); },
 		}
 
@@ -98,15 +111,53 @@ fn main__() -> Result<(), FyiError> {
 			else { Err(FyiError::Passthrough(1)) };
 	}
 
-	// Print to `STDERR`.
-	if flags.stderr() { msg.eprint(); }
-	// Print to `STDOUT`.
-	else { msg.print(); }
+	// Print to `STDERR` or `STDOUT`, as requested.
+	let repeat = flags.repeat();
+	let res =
+		if flags.stderr() { print(&mut std::io::stderr().lock(), &msg, repeat) }
+		else { print(&mut std::io::stdout().lock(), &msg, repeat) };
+
+	// Save a copy too?
+	if let Some(path) = flags.tee() { tee(&msg, path, repeat); }
 
-	// Exit as desired.
+	// A pipe closed early — by `head`, say — means a quiet, successful exit,
+	// same as for blank lines. Otherwise exit as desired.
+	FyiError::from_print(res)?;
 	flags.exit()
 }
 
+/// # Print.
+///
+/// Write the message to the (locked) handle `repeat` times, then flush.
+fn print<W: Write>(handle: &mut W, msg: &Msg, repeat: NonZeroUsize)
+-> std::io::Result<()> {
+	for _ in 0..repeat.get() { handle.write_all(msg.as_bytes())?; }
+	handle.flush()
+}
+
+/// # Tee.
+///
+/// Append `repeat` plain-text copies of the message to the file at `path`,
+/// creating it if need be.
+///
+/// Failure is not fatal; a warning is printed to `STDERR` but execution
+/// otherwise continues as normal.
+fn tee(msg: &Msg, path: &Path, repeat: NonZeroUsize) {
+	let res = std::fs::OpenOptions::new()
+		.create(true)
+		.append(true)
+		.open(path)
+		.and_then(|mut file| {
+			let msg = msg.clone().without_ansi();
+			for _ in 0..repeat.get() { file.write_all(msg.as_bytes())?; }
+			Ok(())
+		});
+
+	if res.is_err() {
+		Msg::warning(format!("Unable to write to {}.", path.display())).eprint();
+	}
+}
+
 #[cold]
 /// # Help Page.
 ///
@@ -115,9 +166,11 @@ fn main__() -> Result<(), FyiError> {
 ///
 /// The contents are generated via `build.rs`, which lowers the runtime cost
 /// and shrinks the binary a touch.
-fn helper(cmd: MsgKind) {
-	use std::io::Write;
-
+///
+/// ## Errors
+///
+/// Write errors (e.g. a closed pipe) are passed back to the caller.
+fn helper(cmd: MsgKind) -> std::io::Result<()> {
 	let writer = std::io::stdout();
 	let mut handle = writer.lock();
 
@@ -132,7 +185,7 @@ fn helper(cmd: MsgKind) {
 	}
 
 	// The top is always the same.
-	write_help!("top").unwrap();
+	write_help!("top")?;
 
 	// The middle section varies by subcommand.
 	match cmd {
@@ -151,7 +204,7 @@ fn helper(cmd: MsgKind) {
 		MsgKind::Task => write_help!("task", true),
 		MsgKind::Warning => write_help!("warning", true),
 		_ => write_help!("help"),
-	}.unwrap();
+	}?;
 
-	handle.flush().unwrap();
+	handle.flush()
 }
@@ -5,20 +5,38 @@ This optional module contains methods for counting the display width of byte str
 */
 
 use crate::iter::NoAnsi;
-use unicode_width::UnicodeWidthChar;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+
+
+/// # Default Tab Stop.
+///
+/// The conventional terminal tab stop, for use with the `*_with_tab_stop`
+/// variants — [`width_with_tab_stop`], [`length_width_with_tab_stop`],
+/// [`Msg::fitted_with_tab_stop`](crate::Msg::fitted_with_tab_stop) — when tabs
+/// should be expanded to the next multiple of this many columns (relative to
+/// the start of the line).
+///
+/// The plain [`width`], [`length_width`], and [`Msg::fitted`](crate::Msg::fitted)
+/// treat tabs like any other control character, i.e. as having no width.
+///
+/// **This requires the `fitted` crate feature.**
+pub const TAB_STOP: usize = 8;
 
 
 
 #[must_use]
+#[inline]
 /// # Length Width.
 ///
 /// Return the maximum byte *length* for the slice that fits a given display
 /// *width*, such that `slice[0..len]` will be a valid substring likely to fit.
 ///
 /// This method accepts raw bytes for performance reasons, but is Unicode-safe;
-/// the return value will always be a valid char boundary. In cases where the
-/// input contains invalid UTF-8, only the leading ASCII bytes will be
-/// considered/counted.
+/// the return value will always be a valid char boundary, and will never fall
+/// in the middle of a grapheme cluster. In cases where the input contains
+/// invalid UTF-8, only the leading ASCII bytes will be considered/counted.
 ///
 /// See the documentation for [`width`] for more information.
 ///
@@ -36,34 +54,78 @@ use unicode_width::UnicodeWidthChar;
 /// );
 /// ```
 pub fn length_width(bytes: &[u8], stop: usize) -> usize {
+	length_width_from(bytes, stop, 0, 0)
+}
+
+#[must_use]
+#[inline]
+/// # Length Width (Custom Tab Stop).
+///
+/// Same as [`length_width`], but with tabs expanded to the next multiple of
+/// `tab_stop` columns, e.g. [`TAB_STOP`]. Passing `0` is equivalent to
+/// [`length_width`].
+///
+/// **This requires the `fitted` crate feature.**
+///
+/// ## Examples
+///
+/// ```
+/// let full: &str = "ab\tcd";
+/// assert_eq!(fyi_msg::length_width(full.as_bytes(), 4), 5);
+/// assert_eq!(fyi_msg::length_width_with_tab_stop(full.as_bytes(), 4, 4), 3);
+/// ```
+pub fn length_width_with_tab_stop(bytes: &[u8], stop: usize, tab_stop: usize)
+-> usize {
+	length_width_from(bytes, stop, 0, tab_stop)
+}
+
+/// # Length Width (From Column).
+///
+/// This is the workhorse behind [`length_width`]. The extra `col` argument
+/// specifies the (line) column the slice begins at, needed for proper tab
+/// expansion.
+pub(crate) fn length_width_from(
+	bytes: &[u8],
+	stop: usize,
+	col: usize,
+	tab_stop: usize,
+) -> usize {
 	// Split on first non-ASCII character.
 	let (a, b): (&[u8], &[u8]) = bytes.iter()
 		.position(|b| ! b.is_ascii())
 		.map_or((bytes, &[]), |pos| bytes.split_at(pos));
 
 	// Short circuit.
-	if a.len() <= stop && b.is_empty() { return bytes.len(); }
+	if
+		a.len() <= stop &&
+		b.is_empty() &&
+		(tab_stop == 0 || ! a.contains(&b'\t'))
+	{
+		return bytes.len();
+	}
 
 	// Iterate through the ASCII parts first, assuming length and width are
 	// equivalent for non-control characters.
-	let mut width = 0;
+	let mut cols = Columns::new(col, tab_stop);
 	let mut iter = NoAnsi::<u8, _>::new(a.iter().copied());
 	while let Some(v) = iter.next() {
-		if v != 0 && ! v.is_ascii_control() {
-			if width == stop { return iter.byte_pos() - 1; }
-			width += 1;
-		}
+		let w = cols.ascii_width(v);
+		// This one won't fit; rewind!
+		if stop < cols.width + w { return iter.byte_pos() - 1; }
+		cols.advance(w);
 	}
 
 	// If we're still here, stringify the rest and keep going!
 	if ! b.is_empty() {
 		let Ok(b) = std::str::from_utf8(b) else { return a.len(); };
-		let mut iter = NoAnsi::<char, _>::new(b.chars());
-		while let Some(v) = iter.next() {
-			width += UnicodeWidthChar::width(v).unwrap_or(0);
-			// This one won't fit; rewind!
-			if stop < width {
-				return a.len() + iter.byte_pos() - v.len_utf8();
+
+		// Add up the clusters between the ANSI sequences.
+		for (pos, span) in visible_spans(b) {
+			for (idx, g) in span.grapheme_indices(true) {
+				let w = cols.grapheme_width(g);
+				// This one won't fit; rewind!
+				if stop < cols.width + w { return a.len() + pos + idx; }
+				cols.advance(w);
 			}
 		}
 	}
@@ -73,6 +135,7 @@ pub fn length_width(bytes: &[u8], stop: usize) -> usize {
 }
 
 #[must_use]
+#[inline]
 /// # Width.
 ///
 /// Find the "display width" of a byte string.
@@ -84,8 +147,12 @@ pub fn length_width(bytes: &[u8], stop: usize) -> usize {
 /// Like anything having to do with width vs length, this should be considered
 /// at best an approximation. For ASCII, every byte that is not a control
 /// character or part of an ANSI [CSI](https://en.wikipedia.org/wiki/ANSI_escape_code#CSI_(Control_Sequence_Introducer)_sequences) or [OSC](https://en.wikipedia.org/wiki/ANSI_escape_code#OSC_(Operating_System_Command)_sequences) sequence
-/// is counted as having a length of 1. For Unicode, the [`unicode_width`](https://crates.io/crates/unicode-width) crate is used
-/// to determine width.
+/// is counted as having a length of 1. For Unicode, the text is split into
+/// grapheme clusters using the [`unicode_segmentation`](https://crates.io/crates/unicode-segmentation) crate, and the
+/// [`unicode_width`](https://crates.io/crates/unicode-width) crate is used to determine the width of each.
+///
+/// Tabs are treated as control characters too, i.e. as having no width. Use
+/// [`width_with_tab_stop`] to expand them instead.
 ///
 /// Note: line breaks are ignored; the cumulative width of all lines is
 /// returned. If you're trying to calculate *line* widths, split the slice
@@ -102,7 +169,37 @@ pub fn length_width(bytes: &[u8], stop: usize) -> usize {
 ///     fyi_msg::width(b"Hello\nWorld"),
 /// );
 /// ```
-pub fn width(bytes: &[u8]) -> usize {
+pub fn width(bytes: &[u8]) -> usize { width_from(bytes, 0, 0) }
+
+#[must_use]
+#[inline]
+/// # Width (Custom Tab Stop).
+///
+/// Same as [`width`], but with tabs expanded to the next multiple of
+/// `tab_stop` columns, relative to the start of the line, e.g. [`TAB_STOP`].
+/// Passing `0` is equivalent to [`width`].
+///
+/// **This requires the `fitted` crate feature.**
+///
+/// ## Examples
+///
+/// ```
+/// use fyi_msg::TAB_STOP;
+///
+/// assert_eq!(fyi_msg::width(b"Hello\tWorld"), 10);
+/// assert_eq!(fyi_msg::width_with_tab_stop(b"Hello\tWorld", TAB_STOP), 13);
+/// assert_eq!(fyi_msg::width_with_tab_stop(b"Hello\tWorld", 4), 13);
+/// assert_eq!(fyi_msg::width_with_tab_stop(b"Hello\tWorld", 0), 10);
+/// ```
+pub fn width_with_tab_stop(bytes: &[u8], tab_stop: usize) -> usize {
+	width_from(bytes, 0, tab_stop)
+}
+
+/// # Width (From Column).
+///
+/// This is the workhorse behind [`width`]. The extra `col` argument specifies
+/// the (line) column the slice begins at, needed for proper tab expansion.
+pub(crate) fn width_from(bytes: &[u8], col: usize, tab_stop: usize) -> usize {
 	// Short circuit.
 	if bytes.is_empty() { return 0; }
 
@@ -113,23 +210,138 @@ pub fn width(bytes: &[u8]) -> usize {
 
 	// For the ASCII half, assume length and width are equivalent, except for
 	// control characters.
-	let mut width: usize = NoAnsi::<u8, _>::new(a.iter().copied())
-		.fold(0, |acc, v|
-			if v == 0 || v.is_ascii_control() { acc }
-			else { acc + 1 }
-		);
+	let mut cols = Columns::new(col, tab_stop);
+	for v in NoAnsi::<u8, _>::new(a.iter().copied()) {
+		let w = cols.ascii_width(v);
+		cols.advance(w);
+	}
 
 	// For the rest (if any), use the Unicode width estimate.
 	if ! b.is_empty() {
 		if let Ok(chars) = std::str::from_utf8(b) {
-			width += NoAnsi::<char, _>::new(chars.chars())
-				.fold(0, |acc, v|
-					UnicodeWidthChar::width(v).map_or(acc, |w| acc + w)
-				);
+			for (_, span) in visible_spans(chars) {
+				for g in span.graphemes(true) {
+					let w = cols.grapheme_width(g);
+					cols.advance(w);
+				}
+			}
+		}
+	}
+
+	cols.width
+}
+
+
+/// # Visible Spans.
+///
+/// Iterate over the stretches of `src` between ANSI sequences, yielding each
+/// along with its starting byte offset.
+///
+/// This lets the grapheme clusters be walked in place, without having to
+/// build a stripped copy of the string first.
+fn visible_spans(src: &str) -> impl Iterator<Item=(usize, &str)> {
+	let mut iter = NoAnsi::<char, _>::new(src.chars());
+	let mut start = 0;
+	let mut end = 0;
+	std::iter::from_fn(move || loop {
+		if let Some(v) = iter.next() {
+			let pos = iter.byte_pos() - v.len_utf8();
+
+			// Still going.
+			if pos == end { end = iter.byte_pos(); }
+			// We skipped over a sequence; return what came before it, if
+			// anything, and start a new span.
+			else {
+				let span = (start, &src[start..end]);
+				start = pos;
+				end = iter.byte_pos();
+				if ! span.1.is_empty() { return Some(span); }
+			}
+		}
+		// Return the last span, if any.
+		else {
+			if start == end { return None; }
+			let span = (start, &src[start..end]);
+			start = end;
+			return Some(span);
+		}
+	})
+}
+
+
+
+#[derive(Debug, Clone, Copy)]
+/// # Column Tracker.
+///
+/// This keeps track of the cumulative display width, as well as the column
+/// relative to the start of the current line, which is needed to figure out
+/// how wide each tab should be.
+struct Columns {
+	/// # Cumulative Width.
+	width: usize,
+
+	/// # Line Column.
+	col: usize,
+
+	/// # Tab Stop.
+	tab_stop: usize,
+}
+
+impl Columns {
+	/// # New.
+	const fn new(col: usize, tab_stop: usize) -> Self {
+		Self { width: 0, col, tab_stop }
+	}
+
+	/// # Advance.
+	///
+	/// Add a (previously calculated) width to the totals.
+	const fn advance(&mut self, width: usize) {
+		self.width += width;
+		self.col += width;
+	}
+
+	/// # ASCII Width.
+	///
+	/// Return the width of an ASCII byte. Line breaks reset the column as a
+	/// side effect.
+	const fn ascii_width(&mut self, v: u8) -> usize {
+		match v {
+			b'\t' => self.tab_width(),
+			b'\n' => {
+				self.col = 0;
+				0
+			},
+			0..=31 | 127 => 0,
+			_ => 1,
+		}
+	}
+
+	/// # Grapheme Width.
+	///
+	/// Return the display width of a single grapheme cluster. Line breaks
+	/// reset the column as a side effect. Tabs are expanded, while other
+	/// control characters are treated as having no width.
+	fn grapheme_width(&mut self, g: &str) -> usize {
+		match g {
+			"\t" => self.tab_width(),
+			"\n" | "\r\n" => {
+				self.col = 0;
+				0
+			},
+			_ =>
+				if g.chars().all(char::is_control) { 0 }
+				else { UnicodeWidthStr::width(g) },
 		}
 	}
 
-	width
+	/// # Tab Width.
+	///
+	/// Return the number of columns needed to reach the next tab stop.
+	const fn tab_width(&self) -> usize {
+		if self.tab_stop == 0 { 0 }
+		else { self.tab_stop - self.col % self.tab_stop }
+	}
 }
 
 
@@ -150,6 +362,13 @@ mod tests {
 			("Björk Guðmundsdóttir", 2, 2),
 			("\x1b[2mBjörk\x1b[0m Guðmundsdóttir", 5, 14),
 			("\x1b[2mHello\x1b[0m World", 5, 13),
+			("a👨‍👩‍👧b", 1, 1),
+			("a👨‍👩‍👧b", 2, 1),
+			("a👨‍👩‍👧b", 3, 19),
+			("ñe\u{301}x", 2, 5),
+			("ñe\u{301}x", 1, 2),
+			("ñ\x1b[1me\x1b[0m\u{301}x", 2, 13),
+			("ab\tcd", 3, 4),
 		] {
 			assert_eq!(
 				length_width(slice.as_bytes(), stop),
@@ -157,6 +376,40 @@ mod tests {
 				"Invalid length/width for {slice:?} fit to {stop}."
 			);
 		}
+
+		// With tab expansion.
+		for &(slice, stop, expected) in &[
+			("ab\tcd", 8, 3),
+			("ab\tcd", 7, 2),
+			("ab\n\tcd", 9, 3),
+			("ab\n\tcd", 10, 4),
+			("\x1b[1mab\tc\x1b[0md", 9, 12),
+			("ñ\tx", 8, 3),
+			("ñ\tx", 7, 2),
+		] {
+			assert_eq!(
+				length_width_with_tab_stop(slice.as_bytes(), stop, TAB_STOP),
+				expected,
+				"Invalid length/width for {slice:?} fit to {stop}."
+			);
+		}
+	}
+
+	#[test]
+	fn t_visible_spans() {
+		for (src, expected) in [
+			("", &[][..]),
+			("Björk", &[(0, "Björk")]),
+			("\x1b[1mBjörk\x1b[0m", &[(4, "Björk")]),
+			("Björk \x1b[2mGuð\x1b[0m!", &[(0, "Björk "), (11, "Guð"), (19, "!")]),
+			("\x1b[1m\x1b[0m", &[]),
+		] {
+			assert_eq!(
+				visible_spans(src).collect::<Vec<_>>(),
+				expected,
+				"Invalid spans for {src:?}.",
+			);
+		}
 	}
 
 	#[test]
@@ -169,6 +422,11 @@ mod tests {
 			("Björk Guðmundsdóttir", 20),
 			("\x1b[2mBjörk\x1b[0m Guðmundsdóttir", 20),
 			("Björk \x1b[2mGuðmundsdóttir\x1b[0m", 20),
+			("a👨‍👩‍👧b", 4),
+			("ñe\u{301}x", 3),
+			("ñ\x1b[1me\x1b[0m\u{301}x", 3),
+			("\tx", 1),
+			("ñ\tx", 2),
 		] {
 			assert_eq!(
 				width(slice.as_bytes()), expected,
@@ -176,4 +434,31 @@ mod tests {
 			);
 		}
 	}
+
+	#[test]
+	fn t_tab_stop() {
+		for &(slice, tab_stop, expected) in &[
+			("\tx", TAB_STOP, 9),
+			("ab\tc", TAB_STOP, 9),
+			("a\n\tb", TAB_STOP, 10),
+			("\x1b[1mab\x1b[0m\tc", TAB_STOP, 9),
+			("ñ\tx", TAB_STOP, 9),
+			("ñ\n\tx", TAB_STOP, 10),
+			("ab\tc", 0, 3),
+			("ab\tc", 2, 5),
+			("ab\tc", 4, 5),
+			("abcd\tc", 4, 9),
+			("ñ\tx", 4, 5),
+		] {
+			assert_eq!(
+				width_with_tab_stop(slice.as_bytes(), tab_stop), expected,
+				"Invalid width for {slice:?} with tab stop {tab_stop}."
+			);
+		}
+
+		// Starting mid-line should affect the first tab.
+		assert_eq!(width_from(b"a\tb", 6, 8), 3);
+		assert_eq!(length_width_from(b"a\tb", 2, 7, 8), 1);
+		assert_eq!(length_width_from(b"a\tb", 2, 6, 8), 2);
+	}
 }
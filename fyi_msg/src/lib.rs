@@ -35,6 +35,22 @@ For more usage examples, check out the `examples/msg` demo, which covers just ab
 | Macro | Equivalent |
 | ----- | ---------- |
 | `confirm!(…)` | `Msg::new(MsgKind::Confirm, "Some question…").prompt()` |
+| `msg_crunched!(…)` | `Msg::crunched(format!(…))` |
+| `msg_debug!(…)` | `Msg::debug(format!(…))` |
+| `msg_done!(…)` | `Msg::done(format!(…))` |
+| `msg_error!(…)` | `Msg::error(format!(…))` |
+| `msg_info!(…)` | `Msg::info(format!(…))` |
+| `msg_notice!(…)` | `Msg::notice(format!(…))` |
+| `msg_review!(…)` | `Msg::review(format!(…))` |
+| `msg_skipped!(…)` | `Msg::skipped(format!(…))` |
+| `msg_success!(…)` | `Msg::success(format!(…))` |
+| `msg_task!(…)` | `Msg::task(format!(…))` |
+| `msg_warning!(…)` | `Msg::warning(format!(…))` |
+
+The message macros carry a `msg_` prefix — `msg_error!` rather than plain
+`error!`, etc. — so they can be glob-imported alongside `log` or `tracing`,
+whose `error!`, `warn!`, `info!`, and `debug!` macros would otherwise collide
+with them.
 
 
 
@@ -44,6 +60,8 @@ For more usage examples, check out the `examples/msg` demo, which covers just ab
 | ------- | ----------- |
 | `fitted` | Enables [`Msg::fitted`] for obtaining a slice trimmed to a specific display width. |
 | `progress` | Enables [`Progless`], a thread-safe CLI progress bar displayer.
+| `syslog` | Enables [`Msg::syslog`] for sending messages to the system log (Unix only). |
+| `termcolor` | Enables [`Msg::write_color`] for writing messages to any `termcolor::WriteColor` stream. |
 | `timestamps` | Enables timestamp-related methods and flags like [`Msg::with_timestamp`]. |
 */
 
@@ -102,55 +120,6 @@ For more usage examples, check out the `examples/msg` demo, which covers just ab
 
 
 
-pub mod iter;
-mod msg;
-#[cfg(feature = "fitted")]   mod fitted;
-#[cfg(feature = "progress")] mod progress;
-
-#[doc(hidden)]
-pub use msg::{
-	buffer::BUFFER2,
-	buffer::BUFFER3,
-	buffer::BUFFER4,
-	buffer::BUFFER5,
-	buffer::BUFFER6,
-	buffer::BUFFER7,
-	buffer::BUFFER8,
-	buffer::BUFFER9,
-	buffer::BUFFER10,
-	buffer::MsgBuffer,
-};
-
-pub use msg::{
-	FLAG_INDENT,
-	FLAG_NEWLINE,
-	kind::MsgKind,
-	Msg,
-};
-
-#[cfg(feature = "fitted")]
-#[cfg_attr(docsrs, doc(cfg(feature = "fitted")))]
-pub use fitted::{
-	length_width,
-	width,
-};
-
-#[cfg(feature = "progress")]
-#[cfg_attr(docsrs, doc(cfg(feature = "progress")))]
-pub use progress::{
-	ba::BeforeAfter,
-	Progless,
-	error::ProglessError,
-};
-
-// Re-export.
-#[cfg_attr(docsrs, doc(cfg(feature = "signal-hook")))]
-#[cfg(feature = "signal-hook")] pub use signal_hook;
-
-#[cfg(feature = "timestamps")]
-#[cfg_attr(docsrs, doc(cfg(feature = "timestamps")))]
-pub use msg::FLAG_TIMESTAMP;
-
 #[macro_use]
 /// # Macros.
 mod macros {
@@ -190,8 +159,72 @@ mod macros {
 	/// if confirm!(yes: "Do you like chickens?", 1) {
 	///     println!("    That's great! They like you too!");
 	/// }
+	///
+	/// // To fall back to the default answer if no input is received after a
+	/// // certain number of seconds, lead with "@timeout N". (All of the other
+	/// // variations are supported too.)
+	/// if confirm!(@timeout 10 yes: "Do you like chickens?") {
+	///     println!("That's great! They like you too!");
+	/// }
+	///
+	/// // To skip the prompt and go with the default answer when STDIN is not
+	/// // a terminal, lead with "@assume_default" instead.
+	/// if confirm!(@assume_default yes: "Do you like chickens?") {
+	///     println!("That's great! They like you too!");
+	/// }
 	/// ```
 	macro_rules! confirm {
+		(@assume_default yes: $text:expr) => (
+			$crate::Msg::new($crate::MsgKind::Confirm, $text).prompt_or_default(true)
+		);
+		(@assume_default yes: $text:expr, $indent:expr) => (
+			$crate::Msg::new($crate::MsgKind::Confirm, $text)
+				.with_indent($indent)
+				.prompt_or_default(true)
+		);
+		(@assume_default no: $text:expr) => (
+			$crate::Msg::new($crate::MsgKind::Confirm, $text).prompt_or_default(false)
+		);
+		(@assume_default no: $text:expr, $indent:expr) => (
+			$crate::Msg::new($crate::MsgKind::Confirm, $text)
+				.with_indent($indent)
+				.prompt_or_default(false)
+		);
+		(@assume_default $text:expr) => (
+			$crate::Msg::new($crate::MsgKind::Confirm, $text).prompt_or_default(false)
+		);
+		(@assume_default $text:expr, $indent:expr) => (
+			$crate::Msg::new($crate::MsgKind::Confirm, $text)
+				.with_indent($indent)
+				.prompt_or_default(false)
+		);
+		(@timeout $secs:tt yes: $text:expr) => (
+			$crate::Msg::new($crate::MsgKind::Confirm, $text)
+				.prompt_with_timeout(true, ::std::time::Duration::from_secs($secs))
+		);
+		(@timeout $secs:tt yes: $text:expr, $indent:expr) => (
+			$crate::Msg::new($crate::MsgKind::Confirm, $text)
+				.with_indent($indent)
+				.prompt_with_timeout(true, ::std::time::Duration::from_secs($secs))
+		);
+		(@timeout $secs:tt no: $text:expr) => (
+			$crate::Msg::new($crate::MsgKind::Confirm, $text)
+				.prompt_with_timeout(false, ::std::time::Duration::from_secs($secs))
+		);
+		(@timeout $secs:tt no: $text:expr, $indent:expr) => (
+			$crate::Msg::new($crate::MsgKind::Confirm, $text)
+				.with_indent($indent)
+				.prompt_with_timeout(false, ::std::time::Duration::from_secs($secs))
+		);
+		(@timeout $secs:tt $text:expr) => (
+			$crate::Msg::new($crate::MsgKind::Confirm, $text)
+				.prompt_with_timeout(false, ::std::time::Duration::from_secs($secs))
+		);
+		(@timeout $secs:tt $text:expr, $indent:expr) => (
+			$crate::Msg::new($crate::MsgKind::Confirm, $text)
+				.with_indent($indent)
+				.prompt_with_timeout(false, ::std::time::Duration::from_secs($secs))
+		);
 		(yes: $text:expr) => (
 			$crate::Msg::new($crate::MsgKind::Confirm, $text).prompt_with_default(true)
 		);
@@ -217,4 +250,138 @@ mod macros {
 				.prompt()
 		);
 	}
+
+	/// # Kind Macros.
+	///
+	/// This generates the `format!`-style convenience macros for the built-in
+	/// message kinds, e.g. `msg_error!`.
+	///
+	/// The `$d` token is just a literal `$`, needed to declare the inner
+	/// macros' own repetitions.
+	macro_rules! kind_macros {
+		($d:tt $( $name:ident $method:ident $label:literal $article:literal, )+) => ($(
+			#[macro_export]
+			#[doc = concat!("# ", $label, ".")]
+			///
+			#[doc = concat!(
+				"This is a convenience macro for generating ", $article, " ", $label,
+				"-prefixed [`Msg`](crate::Msg) — with a trailing line break — from ",
+				"`format!`-style arguments.",
+			)]
+			///
+			/// Lead with `@stdout` or `@stderr` to print the message straight away
+			/// instead of returning it.
+			///
+			/// ## Example
+			///
+			#[doc = concat!(
+				"```no_run\n",
+				"use fyi_msg::{", stringify!($name), ", Msg};\n",
+				"\n",
+				"let path = \"/foo/bar.jpg\";\n",
+				"\n",
+				"// The manual way:\n",
+				"Msg::", stringify!($method), "(format!(\"Hello from {path}.\")).print();\n",
+				"\n",
+				"// The macro way:\n",
+				stringify!($name), "!(\"Hello from {path}.\").print();\n",
+				"\n",
+				"// Or the even shorter macro way:\n",
+				stringify!($name), "!(@stdout \"Hello from {path}.\");\n",
+				"```",
+			)]
+			macro_rules! $name {
+				(@stdout $d($d arg:tt)+) => (
+					$crate::Msg::$method(::std::format!($d($d arg)+)).print()
+				);
+				(@stderr $d($d arg:tt)+) => (
+					$crate::Msg::$method(::std::format!($d($d arg)+)).eprint()
+				);
+				($d($d arg:tt)+) => (
+					$crate::Msg::$method(::std::format!($d($d arg)+))
+				);
+			}
+		)+);
+	}
+
+	// Note: these are all prefixed with `msg_` to keep them from colliding with
+	// the likes of `log::error!` or `tracing::info!` when glob-imported.
+	kind_macros! {
+		$
+		msg_crunched crunched "Crunched" "a",
+		msg_debug    debug    "Debug"    "a",
+		msg_done     done     "Done"     "a",
+		msg_error    error    "Error"    "an",
+		msg_info     info     "Info"     "an",
+		msg_notice   notice   "Notice"   "a",
+		msg_review   review   "Review"   "a",
+		msg_skipped  skipped  "Skipped"  "a",
+		msg_success  success  "Success"  "a",
+		msg_task     task     "Task"     "a",
+		msg_warning  warning  "Warning"  "a",
+	}
 }
+
+
+
+pub mod iter;
+mod msg;
+#[cfg(feature = "fitted")]   mod fitted;
+#[cfg(feature = "progress")] mod progress;
+#[cfg(feature = "fitted")]   mod wrap;
+
+#[doc(hidden)]
+pub use msg::{
+	buffer::BUFFER2,
+	buffer::BUFFER3,
+	buffer::BUFFER4,
+	buffer::BUFFER5,
+	buffer::BUFFER6,
+	buffer::BUFFER7,
+	buffer::BUFFER8,
+	buffer::BUFFER9,
+	buffer::BUFFER10,
+	buffer::MsgBuffer,
+};
+
+pub use msg::{
+	FLAG_INDENT,
+	FLAG_NEWLINE,
+	kind::MsgKind,
+	Msg,
+	prefix::IntoMsgPrefix,
+	prefix::MsgBadge,
+	template::MsgTemplate,
+};
+
+#[cfg(feature = "fitted")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fitted")))]
+pub use fitted::{
+	length_width,
+	length_width_with_tab_stop,
+	TAB_STOP,
+	width,
+	width_with_tab_stop,
+};
+
+#[cfg(feature = "fitted")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fitted")))]
+pub use wrap::MsgWrapWriter;
+
+#[cfg(feature = "progress")]
+#[cfg_attr(docsrs, doc(cfg(feature = "progress")))]
+pub use progress::{
+	ba::BeforeAfter,
+	Progless,
+	error::ProglessError,
+	event::ProglessEvent,
+	snapshot::ProglessSnapshot,
+};
+
+// Re-export.
+#[cfg_attr(docsrs, doc(cfg(feature = "signal-hook")))]
+#[cfg(feature = "signal-hook")] pub use signal_hook;
+
+#[cfg(feature = "timestamps")]
+#[cfg_attr(docsrs, doc(cfg(feature = "timestamps")))]
+pub use msg::FLAG_TIMESTAMP;
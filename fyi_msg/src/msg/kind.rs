@@ -123,6 +123,49 @@ impl MsgKind {
 		}
 	}
 
+	#[must_use]
+	/// # Severity.
+	///
+	/// Return the severity level of the kind, for filtering purposes, e.g. to
+	/// implement a `--log-level` option. Higher is more severe.
+	///
+	/// The ranking is as follows, and will not change:
+	///
+	/// | Severity | Kind(s) |
+	/// | -------- | ------- |
+	/// | 5 | [`MsgKind::Error`] |
+	/// | 4 | [`MsgKind::Warning`] |
+	/// | 3 | [`MsgKind::Notice`] |
+	/// | 2 | [`MsgKind::Info`], plus the outcome/status kinds: [`MsgKind::Confirm`], [`MsgKind::Crunched`], [`MsgKind::Done`], [`MsgKind::Review`], [`MsgKind::Skipped`], [`MsgKind::Success`], and [`MsgKind::Task`] |
+	/// | 1 | [`MsgKind::Debug`] |
+	/// | 0 | [`MsgKind::None`] (no severity) |
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::MsgKind;
+	///
+	/// assert!(MsgKind::Error.severity() > MsgKind::Warning.severity());
+	/// assert_eq!(MsgKind::Success.severity(), MsgKind::Info.severity());
+	///
+	/// // Show warnings and up.
+	/// let min = MsgKind::Warning.severity();
+	/// assert!(MsgKind::Error.severity() >= min);
+	/// assert!(MsgKind::Notice.severity() < min);
+	/// ```
+	pub const fn severity(self) -> u8 {
+		match self {
+			#[cfg(feature = "bin_kinds")] Self::None | Self::Blank | Self::Custom => 0,
+			#[cfg(not(feature = "bin_kinds"))] Self::None => 0,
+			Self::Debug => 1,
+			Self::Confirm | Self::Crunched | Self::Done | Self::Info | Self::Review |
+			Self::Skipped | Self::Success | Self::Task => 2,
+			Self::Notice => 3,
+			Self::Warning => 4,
+			Self::Error => 5,
+		}
+	}
+
 	#[must_use]
 	/// # Length.
 	///
@@ -205,4 +248,27 @@ mod tests {
 			assert_eq!(p.is_empty(), p.as_bytes().is_empty());
 		}
 	}
+
+	#[test]
+	fn t_severity() {
+		assert_eq!(MsgKind::None.severity(), 0);
+		assert_eq!(MsgKind::Debug.severity(), 1);
+		assert_eq!(MsgKind::Info.severity(), 2);
+		assert_eq!(MsgKind::Notice.severity(), 3);
+		assert_eq!(MsgKind::Warning.severity(), 4);
+		assert_eq!(MsgKind::Error.severity(), 5);
+
+		// Outcomes rank with Info.
+		for p in [
+			MsgKind::Confirm,
+			MsgKind::Crunched,
+			MsgKind::Done,
+			MsgKind::Review,
+			MsgKind::Skipped,
+			MsgKind::Success,
+			MsgKind::Task,
+		] {
+			assert_eq!(p.severity(), MsgKind::Info.severity());
+		}
+	}
 }
@@ -4,8 +4,12 @@
 
 pub(super) mod buffer;
 pub(super) mod kind;
+pub(super) mod prefix;
+pub(super) mod template;
 
 use crate::{
+	IntoMsgPrefix,
+	MsgBadge,
 	iter::NoAnsi,
 	MsgKind,
 	MsgBuffer,
@@ -13,17 +17,36 @@ use crate::{
 
 #[cfg(feature = "progress")] use crate::BeforeAfter;
 
-use dactyl::NiceU8;
+use dactyl::{
+	NiceU8,
+	NiceU64,
+	traits::SaturatingFrom,
+};
 use std::{
-	borrow::Borrow,
+	borrow::{
+		Borrow,
+		Cow,
+	},
 	fmt,
 	hash,
-	io,
+	io::{
+		self,
+		IsTerminal,
+	},
 	ops::Deref,
+	str::Utf8Error,
+	sync::{
+		mpsc::{
+			self,
+			RecvTimeoutError,
+		},
+		Mutex,
+		OnceLock,
+		PoisonError,
+	},
+	time::Duration,
 };
 
-#[cfg(feature = "fitted")] use std::borrow::Cow;
-
 
 
 #[cfg(feature = "timestamps")]
@@ -34,6 +57,11 @@ const MSGBUFFER: usize = crate::BUFFER6;
 /// # Message Buffer Length.
 const MSGBUFFER: usize = crate::BUFFER5;
 
+/// # Sixteen Spaces.
+///
+/// Enough for the maximum (four) levels of indentation.
+static SPACES: [u8; 16] = [32_u8; 16];
+
 
 
 #[cfg(feature = "timestamps")]
@@ -123,7 +151,7 @@ macro_rules! impl_builtins {
 			v.extend_from_slice(msg);
 			v.push(b'\n');
 
-			Self(MsgBuffer::from_raw_parts(v, new_toc!($p_len, m_end, true)))
+			Self(MsgBuffer::from_raw_parts(v, new_toc!($p_len, m_end, true)), 0)
 		}
 	);
 }
@@ -189,6 +217,7 @@ pub const FLAG_NEWLINE: u8 =   0b0100;
 /// of additional dependencies):
 ///
 /// * `fitted` adds the [`Msg::fitted`] method, which returns a byte slice that should fit within a given display width, shrinking the message part of the message as necessary to make room (leaving prefixes and suffixes in tact).
+///   It also teaches the `Display` implementation to honor width/fill/alignment flags, e.g. `format!("{msg:>30}")`.
 /// * `timestamps` adds [`Msg::with_timestamp`] and [`Msg::set_timestamp`] methods for adding a local datetime value before the prefix.
 ///
 /// Everything else comes stock!
@@ -221,7 +250,15 @@ pub const FLAG_NEWLINE: u8 =   0b0100;
 /// `AsRef<[u8]>`. They also implement `AsRef<str>` and
 /// `Borrow<str>` for stringy situations. And if you want to consume the struct
 /// into an owned type, there's also [`Msg::into_vec`] and [`Msg::into_string`].
-pub struct Msg(MsgBuffer<MSGBUFFER>);
+pub struct Msg(
+	MsgBuffer<MSGBUFFER>,
+
+	/// # Block Indent Level.
+	///
+	/// The level last passed to `set_block_indent`, so that its spaces can be
+	/// swapped out rather than piled on if it is called again.
+	u8,
+);
 
 impl AsRef<[u8]> for Msg {
 	#[inline]
@@ -245,10 +282,43 @@ impl Deref for Msg {
 }
 
 impl fmt::Display for Msg {
+	#[cfg(not(feature = "fitted"))]
 	#[inline]
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		f.write_str(self.as_str())
 	}
+
+	#[cfg(feature = "fitted")]
+	/// # Display.
+	///
+	/// When the `fitted` crate feature is enabled, this will honor any width,
+	/// fill, and alignment formatting flags, padding according to the
+	/// _display_ width of the message rather than its byte length.
+	///
+	/// Padding is applied before the trailing line break, if any.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		use fmt::Write;
+
+		// Figure out how much padding is needed, if any.
+		let pad = f.width()
+			.and_then(|w| w.checked_sub(crate::width(self)))
+			.unwrap_or(0);
+		if pad == 0 { return f.write_str(self.as_str()); }
+
+		let (before, after) = match f.align() {
+			Some(fmt::Alignment::Right) => (pad, 0),
+			Some(fmt::Alignment::Center) => (pad / 2, pad - pad / 2),
+			Some(fmt::Alignment::Left) | None => (0, pad),
+		};
+
+		// Split off the line break so the padding can go before it.
+		let (body, newline) = self.as_str().split_at(self.0.start(PART_NEWLINE) as usize);
+		let fill = f.fill();
+		for _ in 0..before { f.write_char(fill)?; }
+		f.write_str(body)?;
+		for _ in 0..after { f.write_char(fill)?; }
+		f.write_str(newline)
+	}
 }
 
 impl From<&str> for Msg {
@@ -261,6 +331,15 @@ impl From<String> for Msg {
 	fn from(src: String) -> Self { Self::plain(src) }
 }
 
+impl From<Cow<'_, str>> for Msg {
+	#[inline]
+	/// # From `Cow<str>`.
+	///
+	/// Owned strings are moved into the buffer as-is; only borrowed ones
+	/// need to be copied.
+	fn from(src: Cow<'_, str>) -> Self { Self::plain(src) }
+}
+
 impl Eq for Msg {}
 
 impl hash::Hash for Msg {
@@ -293,6 +372,41 @@ impl PartialEq<Vec<u8>> for Msg {
 	fn eq(&self, other: &Vec<u8>) -> bool { self.0 == *other }
 }
 
+/// ## Constants.
+impl Msg {
+	/// # ANSI Reset.
+	///
+	/// The sequence used to clear ANSI formatting at the end of prefixes,
+	/// timestamps, trimmed messages, etc.
+	pub const RESET: &'static str = "\x1b[0m";
+
+	/// # Prefix Separator.
+	///
+	/// The punctuation used to separate prefixes from the message body.
+	///
+	/// Note that this exact string only appears in unformatted output, e.g.
+	/// after [`Msg::without_ansi`]. For formatted prefixes, the
+	/// [`Msg::RESET`] sequence sits between the colon and the space; see
+	/// [`Msg::SEPARATOR_ANSI`] for the sequence as actually rendered.
+	pub const SEPARATOR: &'static str = ": ";
+
+	/// # Prefix Separator (Formatted).
+	///
+	/// The sequence separating formatted prefixes — built-in or custom — from
+	/// the message body, as it actually appears in the output: the colon, a
+	/// [`Msg::RESET`], then the space.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::Msg;
+	///
+	/// let msg = Msg::custom("Prefix", 199, "Hello World");
+	/// assert!(msg.prefix().ends_with(Msg::SEPARATOR_ANSI));
+	/// ```
+	pub const SEPARATOR_ANSI: &'static str = ":\x1b[0m ";
+}
+
 /// ## Instantiation.
 impl Msg {
 	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
@@ -323,7 +437,85 @@ impl Msg {
 		buf.extend_from_slice(kind.as_bytes());
 		buf.extend_from_slice(msg);
 
-		Self(MsgBuffer::from_raw_parts(buf, new_toc!(p_end, m_end)))
+		Self(MsgBuffer::from_raw_parts(buf, new_toc!(p_end, m_end)), 0)
+	}
+
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	#[expect(clippy::needless_pass_by_value, reason = "Prefixes are usually cheap temporaries.")]
+	#[must_use]
+	/// # New Message (Owned).
+	///
+	/// This is the same as [`Msg::new`], except it takes ownership of the
+	/// message `String`, reusing its allocation for the buffer rather than
+	/// copying it into a new one.
+	///
+	/// This can save a bit of overhead when working with large, dynamically
+	/// generated messages that aren't needed afterward.
+	///
+	/// Unlike [`Msg::new`], the prefix can also be a custom `(label, color)`
+	/// pair. See [`IntoMsgPrefix`] for more details.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::{Msg, MsgKind};
+	///
+	/// let body = format!("Found {} matching files.", 5000);
+	/// let msg = Msg::new_owned(MsgKind::Info, body);
+	///
+	/// // Custom prefixes work too.
+	/// let body = format!("Found {} matching files.", 5000);
+	/// let msg = Msg::new_owned(("Search", 199), body);
+	/// ```
+	pub fn new_owned<P>(prefix: P, msg: String) -> Self
+	where P: IntoMsgPrefix {
+		let mut buf = msg.into_bytes();
+		let p_end = prefix.prefix_len() as u32;
+		let m_end = p_end + buf.len() as u32;
+
+		// Tack the prefix onto the end, if any, then rotate it to the front.
+		if p_end != 0 {
+			let len = buf.len();
+			prefix.prefix_push(&mut buf);
+			buf.rotate_left(len);
+		}
+
+		Self(MsgBuffer::from_raw_parts(buf, new_toc!(p_end, m_end)), 0)
+	}
+
+	#[must_use]
+	#[inline]
+	/// # New Message (Sanitized).
+	///
+	/// This is the same as [`Msg::new`], except any ANSI sequences or control
+	/// characters (other than tabs) are stripped from the message first,
+	/// line breaks included.
+	///
+	/// Use this instead of [`Msg::new`] whenever the message contains
+	/// untrusted input — file names, URLs, user data, etc. — to keep it from
+	/// injecting cursor movements, color bombs, or fake lines into the output.
+	///
+	/// Only the message is sanitized; custom prefix labels are used as-is.
+	/// To mix trusted text with an untrusted value, see
+	/// [`Msg::with_quoted_arg`]. For untrusted suffixes, see
+	/// [`Msg::with_sanitized_suffix`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::{Msg, MsgKind};
+	///
+	/// let name = "evil\x1b[2J\rname.txt";
+	/// let msg = Msg::new_sanitized(MsgKind::Error, name);
+	/// assert_eq!(msg.message(), "evilname.txt");
+	///
+	/// // Custom prefixes work too.
+	/// let msg = Msg::new_sanitized(("Upload", 199), name);
+	/// assert_eq!(msg.message(), "evilname.txt");
+	/// ```
+	pub fn new_sanitized<P, S>(prefix: P, msg: S) -> Self
+	where P: IntoMsgPrefix, S: AsRef<str> {
+		Self::new_owned(prefix, sanitize(msg.as_ref()))
 	}
 
 	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
@@ -359,11 +551,11 @@ impl Msg {
 		buf.extend_from_slice(color.as_bytes());
 		buf.push(b'm');
 		buf.extend_from_slice(prefix);
-		buf.extend_from_slice(b":\x1b[0m ");
+		buf.extend_from_slice(Self::SEPARATOR_ANSI.as_bytes());
 		buf.extend_from_slice(msg);
 
 		let p_end = m_end - msg.len() as u32;
-		Self(MsgBuffer::from_raw_parts(buf, new_toc!(p_end, m_end)))
+		Self(MsgBuffer::from_raw_parts(buf, new_toc!(p_end, m_end)), 0)
 	}
 
 	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
@@ -399,7 +591,7 @@ impl Msg {
 		buf.extend_from_slice(prefix);
 		buf.extend_from_slice(msg);
 
-		Self(MsgBuffer::from_raw_parts(buf, new_toc!(p_end, m_end)))
+		Self(MsgBuffer::from_raw_parts(buf, new_toc!(p_end, m_end)), 0)
 	}
 
 	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
@@ -419,7 +611,206 @@ impl Msg {
 		let msg = msg.into().into_bytes();
 		let len = msg.len() as u32;
 
-		Self(MsgBuffer::from_raw_parts(msg, new_toc!(0, len)))
+		Self(MsgBuffer::from_raw_parts(msg, new_toc!(0, len)), 0)
+	}
+
+	#[must_use]
+	#[inline]
+	/// # New Blank Lines.
+	///
+	/// Return a message consisting of nothing but `n` line breaks, i.e. the
+	/// library equivalent of `fyi blank`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::Msg;
+	///
+	/// let msg = Msg::blank_lines(3);
+	/// assert_eq!(msg.as_str(), "\n\n\n");
+	///
+	/// // Print them!
+	/// msg.print();
+	/// ```
+	pub fn blank_lines(n: usize) -> Self { Self::plain("\n".repeat(n)) }
+
+	#[must_use]
+	#[inline]
+	/// # New Key/Value Pair.
+	///
+	/// Create a new message from a key/value pair, with the (dimmed) key and
+	/// a colon occupying the prefix, and the value the message body.
+	///
+	/// For a whole set of pairs with the values lined up, see
+	/// [`Msg::kv_aligned`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::Msg;
+	///
+	/// let msg = Msg::kv("Threads", "8");
+	/// assert_eq!(msg.prefix(), "\x1b[2mThreads:\x1b[0m ");
+	/// assert_eq!(msg.message(), "8");
+	/// ```
+	pub fn kv(key: &str, value: &str) -> Self { Self::kv__(key, value, 0) }
+
+	#[cfg(feature = "fitted")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "fitted")))]
+	#[must_use]
+	/// # New Key/Value Pairs (Aligned).
+	///
+	/// Same as [`Msg::kv`], but for a set of pairs, with the keys padded to
+	/// the (display) width of the widest so the values all line up.
+	///
+	/// Each message is given a trailing line break.
+	///
+	/// **This requires the `fitted` crate feature.**
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::Msg;
+	///
+	/// let msgs = Msg::kv_aligned(&[("Threads", "8"), ("Mode", "Fast")]);
+	/// assert_eq!(msgs[0].as_str(), "\x1b[2mThreads:\x1b[0m 8\n");
+	/// assert_eq!(msgs[1].as_str(), "\x1b[2mMode:\x1b[0m    Fast\n");
+	///
+	/// // Print them all at once.
+	/// Msg::print_all(&msgs);
+	/// ```
+	pub fn kv_aligned(pairs: &[(&str, &str)]) -> Vec<Self> {
+		let widths: Vec<usize> = pairs.iter()
+			.map(|(k, _)| crate::width(k.as_bytes()))
+			.collect();
+		let max = widths.iter().copied().max().unwrap_or(0);
+
+		pairs.iter()
+			.zip(widths)
+			.map(|((k, v), w)| Self::kv__(k, v, max - w).with_newline(true))
+			.collect()
+	}
+
+	/// # New Key/Value Pair (w/ Padding).
+	///
+	/// This builds the key/value message, adding `pad` extra spaces after the
+	/// key.
+	fn kv__(key: &str, value: &str, pad: usize) -> Self {
+		let mut prefix = String::with_capacity(key.len() + pad + 9);
+		prefix.push_str("\x1b[2m");
+		prefix.push_str(key);
+		prefix.push_str(Self::SEPARATOR_ANSI);
+		prefix.extend(std::iter::repeat_n(' ', pad));
+
+		let mut out = Self::plain(value);
+		out.0.replace(PART_PREFIX, prefix.as_bytes());
+		out
+	}
+
+	#[cfg(feature = "fitted")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "fitted")))]
+	#[must_use]
+	#[inline]
+	/// # New Horizontal Rule.
+	///
+	/// Return a (dimmed) line of `ch` spanning the width of the terminal,
+	/// useful for separating sections of output. A trailing line break is
+	/// included.
+	///
+	/// The width is taken from `STDOUT` when the `terminal_size` dependency
+	/// is enabled (as it is with the `progress` feature), otherwise — or if
+	/// the width cannot be determined — 80 columns is assumed.
+	///
+	/// See also [`Msg::rule_titled`].
+	///
+	/// **This requires the `fitted` crate feature.**
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Msg;
+	///
+	/// Msg::rule('─').print();
+	/// ```
+	pub fn rule(ch: char) -> Self { Self::rule__(ch, "", Self::rule_width()) }
+
+	#[cfg(feature = "fitted")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "fitted")))]
+	#[must_use]
+	#[inline]
+	/// # New Horizontal Rule (Titled).
+	///
+	/// Same as [`Msg::rule`], but drawn with `─` and with the title centered
+	/// within it, like `──── Section ────`.
+	///
+	/// If the title is too long to leave any room for the rule, it is
+	/// printed by itself, cropped to fit.
+	///
+	/// **This requires the `fitted` crate feature.**
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Msg;
+	///
+	/// Msg::rule_titled("Results").print();
+	/// ```
+	pub fn rule_titled<S>(title: S) -> Self
+	where S: AsRef<str> {
+		Self::rule__('─', title.as_ref(), Self::rule_width())
+	}
+
+	#[cfg(feature = "fitted")]
+	/// # New Horizontal Rule (w/ Width).
+	///
+	/// This builds the rule for `rule` and `rule_titled`.
+	fn rule__(ch: char, title: &str, width: usize) -> Self {
+		let mut buf = [0_u8; 4];
+		let ch_width = crate::width(ch.encode_utf8(&mut buf).as_bytes()).max(1);
+		let title = title.trim();
+		let title_width = crate::width(title.as_bytes());
+
+		let mut out = String::with_capacity(width * ch.len_utf8() + title.len() + 24);
+
+		// Just the rule.
+		if title.is_empty() {
+			out.push_str("\x1b[2m");
+			out.extend(std::iter::repeat_n(ch, width / ch_width));
+		}
+		// Just the title.
+		else if width < title_width + 2 + ch_width * 2 {
+			out.push_str("\x1b[1m");
+			out.push_str(&title[..crate::length_width(title.as_bytes(), width)]);
+		}
+		// Both!
+		else {
+			let cells = (width - title_width - 2) / ch_width;
+			let left = cells / 2;
+			out.push_str("\x1b[2m");
+			out.extend(std::iter::repeat_n(ch, left));
+			out.push_str("\x1b[0;1m ");
+			out.push_str(title);
+			out.push_str(" \x1b[0;2m");
+			out.extend(std::iter::repeat_n(ch, cells - left));
+		}
+
+		out.push_str("\x1b[0m");
+		Self::plain(out).with_newline(true)
+	}
+
+	#[cfg(feature = "fitted")]
+	#[cfg_attr(
+		not(feature = "terminal_size"),
+		expect(clippy::missing_const_for_fn, reason = "Depends on features."),
+	)]
+	/// # Rule Width.
+	///
+	/// Return the terminal width, or `80` if it can't be determined.
+	fn rule_width() -> usize {
+		#[cfg(feature = "terminal_size")]
+		if let Some((w, _)) = terminal_size::terminal_size() { return usize::from(w.0); }
+
+		80
 	}
 }
 
@@ -506,6 +897,31 @@ impl Msg {
 		self
 	}
 
+	#[must_use]
+	#[inline]
+	/// # With Block Indent.
+	///
+	/// Same as [`Msg::with_indent`], except that every line of a multi-line
+	/// message body is indented, not just the first, as with block quotes.
+	///
+	/// The extra indentation is baked into the message itself, so this should
+	/// be called _after_ the message is set. (Calling it again later replaces
+	/// the previous block indentation rather than adding to it.)
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::Msg;
+	///
+	/// let msg = Msg::plain("Roses are red,\nViolets are blue.")
+	///     .with_block_indent(1);
+	/// assert_eq!(msg.as_str(), "    Roses are red,\n    Violets are blue.");
+	/// ```
+	pub fn with_block_indent(mut self, indent: u8) -> Self {
+		self.set_block_indent(indent);
+		self
+	}
+
 	#[cfg(feature = "timestamps")]
 	#[cfg_attr(docsrs, doc(cfg(feature = "timestamps")))]
 	#[must_use]
@@ -592,6 +1008,31 @@ impl Msg {
 		self
 	}
 
+	#[must_use]
+	/// # With Badge Prefix.
+	///
+	/// Set or reset the message with a user-defined "badge" prefix: bold
+	/// white text on a background of the given ANSI-256 color, padded with a
+	/// space on either side, e.g. a white-on-red ` ERROR ` for messages that
+	/// really need to stand out.
+	///
+	/// Unlike regular prefixes, badges are not followed by a colon.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::Msg;
+	///
+	/// let msg = Msg::plain("The server is on fire!")
+	///     .with_badge_prefix("ERROR", 9);
+	/// assert_eq!(msg.prefix(), "\x1b[1;97;48;5;9m ERROR \x1b[0m ");
+	/// ```
+	pub fn with_badge_prefix<S>(mut self, prefix: S, color: u8) -> Self
+	where S: AsRef<str> {
+		self.set_badge_prefix(prefix, color);
+		self
+	}
+
 	#[must_use]
 	#[inline]
 	/// # With Message.
@@ -613,6 +1054,81 @@ impl Msg {
 		self
 	}
 
+	#[must_use]
+	#[inline]
+	/// # With Link.
+	///
+	/// Wrap the current message part in an [OSC 8](https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda)
+	/// hyperlink pointing to `url`, making the text clickable in terminals
+	/// that support it. Terminals that don't will just show the text.
+	///
+	/// The link sits inside any styling applied to the message, so colors and
+	/// such are unaffected.
+	///
+	/// Note that the message itself must be set _first_; calling
+	/// [`Msg::set_msg`] afterward will replace the link along with the text.
+	///
+	/// If the URL is empty or contains control characters, or there is no
+	/// message to link, the message is left unchanged.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::Msg;
+	///
+	/// let msg = Msg::error("Invalid configuration.")
+	///     .with_link("https://example.com/docs/config");
+	///
+	/// assert_eq!(
+	///     msg.message(),
+	///     "\x1b]8;;https://example.com/docs/config\x1b\\Invalid configuration.\x1b]8;;\x1b\\",
+	/// );
+	///
+	/// // The link disappears with the rest of the ANSI.
+	/// assert_eq!(msg.without_ansi().message(), "Invalid configuration.");
+	/// ```
+	pub fn with_link(mut self, url: &str) -> Self {
+		self.set_link(url);
+		self
+	}
+
+	#[must_use]
+	#[inline]
+	/// # With Section Markers.
+	///
+	/// Wrap the current message part in [OSC 133](https://gitlab.freedesktop.org/Per_Bothner/specifications/blob/master/proposals/semantic-prompts.md)
+	/// shell-integration markers — `\x1b]133;A\x07` before, `\x1b]133;C\x07`
+	/// after — so users of terminals with shell integration can jump between
+	/// sections of output.
+	///
+	/// This is strictly opt-in, as not every terminal handles the markers
+	/// gracefully.
+	///
+	/// As with [`Msg::with_link`], the message itself must be set _first_;
+	/// calling [`Msg::set_msg`] afterward will replace the markers along with
+	/// the text. Calling this more than once has no additional effect.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::Msg;
+	///
+	/// let msg = Msg::task("Building assets…")
+	///     .with_section_markers();
+	///
+	/// assert_eq!(
+	///     msg.message(),
+	///     "\x1b]133;A\x07Building assets…\x1b]133;C\x07",
+	/// );
+	///
+	/// // The markers disappear with the rest of the ANSI.
+	/// assert_eq!(msg.without_ansi().message(), "Building assets…");
+	/// ```
+	pub fn with_section_markers(mut self) -> Self {
+		self.set_section_markers();
+		self
+	}
+
 	#[must_use]
 	#[inline]
 	/// # With Suffix.
@@ -641,43 +1157,272 @@ impl Msg {
 	}
 
 	#[must_use]
-	/// # Without ANSI Formatting.
+	#[inline]
+	/// # With Suffix (Sanitized).
 	///
-	/// Strip any ANSI formatting from the message.
+	/// This is the same as [`Msg::with_suffix`], except any ANSI sequences or
+	/// control characters (other than tabs) are stripped first, the same as
+	/// [`Msg::new_sanitized`] does for the message.
 	///
-	/// Note that subsequent changes might re-introduce ANSI formatting, so
-	/// this should generally be the last operation before display.
+	/// ## Examples
 	///
-	/// For unchained usage, see [`Msg::strip_ansi`].
+	/// ```
+	/// use fyi_msg::Msg;
+	///
+	/// let msg = Msg::plain("Downloaded")
+	///     .with_sanitized_suffix(" evil\x1b[2J\rname.txt");
+	/// assert_eq!(msg.suffix(), " evilname.txt");
+	/// ```
+	pub fn with_sanitized_suffix<S>(mut self, suffix: S) -> Self
+	where S: AsRef<str> {
+		self.set_sanitized_suffix(suffix);
+		self
+	}
+
+	#[must_use]
+	#[inline]
+	/// # With Note.
+	///
+	/// Set the message suffix to a dimmed, parenthetical note, like
+	/// `" (note)"`, for secondary context that shouldn't compete with the
+	/// message itself.
+	///
+	/// This occupies the same slot as [`Msg::with_suffix`], so will replace
+	/// any existing suffix (and vice versa). An empty note clears it.
 	///
 	/// ## Examples
 	///
-	/// ```no_run
+	/// ```
 	/// use fyi_msg::Msg;
 	///
-	/// Msg::info("5,000 matching files were found.")
-	///     .without_ansi()
-	///     .print();
+	/// let msg = Msg::plain("Uploaded 5 files.")
+	///     .with_note("2 skipped");
+	/// assert_eq!(msg.suffix(), " \x1b[2m(2 skipped)\x1b[0m");
 	/// ```
-	pub fn without_ansi(mut self) -> Self {
-		self.strip_ansi();
+	pub fn with_note<S>(mut self, note: S) -> Self
+	where S: AsRef<str> {
+		self.set_note(note);
 		self
 	}
-}
 
-/// ## Setters.
-impl Msg {
-	/// # Set Indentation.
+	#[must_use]
+	#[inline]
+	/// # With Quoted Argument.
+	///
+	/// Append `arg` to the message body, wrapped in quotes and styled bold and
+	/// underlined so it stands apart from the surrounding text. This is the
+	/// standard way to echo user-supplied values — file names, URLs, etc. —
+	/// back to the user.
+	///
+	/// For safety, any ANSI sequences or control characters within `arg` are
+	/// stripped first, so untrusted input can't sneak in cursor movements,
+	/// color bombs, and the like.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::Msg;
+	///
+	/// let msg = Msg::error("Could not find ")
+	///     .with_quoted_arg("foo\x1b[2J.txt");
+	/// assert_eq!(
+	///     msg.message(),
+	///     "Could not find '\x1b[1;4mfoo.txt\x1b[0m'",
+	/// );
+	/// ```
+	pub fn with_quoted_arg<S>(mut self, arg: S) -> Self
+	where S: AsRef<str> {
+		self.push_quoted_arg(arg);
+		self
+	}
+
+	#[must_use]
+	/// # Without ANSI Formatting.
+	///
+	/// Strip any ANSI formatting from the message.
+	///
+	/// Note that subsequent changes might re-introduce ANSI formatting, so
+	/// this should generally be the last operation before display.
+	///
+	/// For unchained usage, see [`Msg::strip_ansi`].
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Msg;
+	///
+	/// Msg::info("5,000 matching files were found.")
+	///     .without_ansi()
+	///     .print();
+	/// ```
+	pub fn without_ansi(mut self) -> Self {
+		self.strip_ansi();
+		self
+	}
+
+	#[cfg(feature = "fitted")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "fitted")))]
+	#[must_use]
+	#[inline]
+	/// # Normalized.
+	///
+	/// Apply [NFC](https://unicode.org/reports/tr15/) normalization to the
+	/// message body, composing any decomposed character sequences — `e` plus
+	/// a combining acute accent, say — into their precomposed equivalents.
+	///
+	/// Decomposed and precomposed text look the same but don't always
+	/// measure the same, so this is worth doing before any width-sensitive
+	/// operations like [`Msg::fitted`] when the input comes from who knows
+	/// where.
+	///
+	/// **This requires the `fitted` crate feature.**
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::Msg;
+	///
+	/// let a = Msg::plain("Cafe\u{301}").normalized();
+	/// let b = Msg::plain("Caf\u{e9}");
+	/// assert_eq!(a, b);
+	/// ```
+	pub fn normalized(mut self) -> Self {
+		self.normalize();
+		self
+	}
+}
+
+/// ## Setters.
+impl Msg {
+	/// # Set Indentation.
 	///
 	/// This is the setter companion to the [`Msg::with_indent`] builder
 	/// method. Refer to that documentation for more information.
 	pub fn set_indent(&mut self, indent: u8) {
-		/// # Sixteen Spaces.
-		static SPACES: [u8; 16] = [32_u8; 16];
-
 		self.0.replace(PART_INDENT, &SPACES[0..4.min(usize::from(indent)) * 4]);
 	}
 
+	/// # Set Block Indentation.
+	///
+	/// This is the setter companion to the [`Msg::with_block_indent`] builder
+	/// method. Refer to that documentation for more information.
+	pub fn set_block_indent(&mut self, indent: u8) {
+		let indent = indent.min(4);
+		self.set_indent(indent);
+
+		// Swap out the old spaces for the new ones.
+		let old = std::mem::replace(&mut self.1, indent);
+		let msg = self.0.get(PART_MSG);
+		if old != indent && msg.contains(&b'\n') {
+			let old = &SPACES[0..usize::from(old) * 4];
+			let spaces = &SPACES[0..usize::from(indent) * 4];
+			let mut new = Vec::with_capacity(msg.len() + spaces.len() * 4);
+			for (i, mut line) in msg.split(|&b| b == b'\n').enumerate() {
+				if i != 0 {
+					new.push(b'\n');
+					line = line.strip_prefix(old).unwrap_or(line);
+
+					// Leave empty lines empty.
+					if ! line.is_empty() { new.extend_from_slice(spaces); }
+				}
+				new.extend_from_slice(line);
+			}
+			self.0.replace(PART_MSG, &new);
+		}
+	}
+
+	/// # Limit Lines.
+	///
+	/// Truncate the message part to (at most) `max` lines, replacing any
+	/// excess with a single dimmed `…(N more lines)` indicator. This is
+	/// useful for surfacing large captures — subprocess `STDERR`, etc. —
+	/// without flooding the terminal.
+	///
+	/// The indicator inherits the leading whitespace of the last line kept,
+	/// so it lines up nicely with [block-indented](Msg::with_block_indent)
+	/// bodies.
+	///
+	/// A single trailing line break is not counted as an extra (empty) line.
+	///
+	/// With the `fitted` crate feature enabled, the indicator is also trimmed
+	/// as needed to keep it from wrapping. The width is taken from `STDOUT`
+	/// when the `terminal_size` dependency is enabled, otherwise — or if the
+	/// width cannot be determined — 80 columns is assumed.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::Msg;
+	///
+	/// let mut msg = Msg::error("One\nTwo\nThree\nFour");
+	/// msg.limit_lines(2);
+	/// assert_eq!(
+	///     msg.message(),
+	///     "One\nTwo\n\x1b[2m…(2 more lines)\x1b[0m",
+	/// );
+	/// ```
+	pub fn limit_lines(&mut self, max: usize) {
+		#[cfg(feature = "fitted")] let width = Self::rule_width();
+		#[cfg(not(feature = "fitted"))] let width = usize::MAX;
+		self.limit_lines__(max, width);
+	}
+
+	#[cfg_attr(
+		not(feature = "fitted"),
+		expect(unused_variables, reason = "Width only matters when fitted."),
+	)]
+	/// # Limit Lines (w/ Width).
+	///
+	/// This handles the actual limiting for `limit_lines`.
+	fn limit_lines__(&mut self, max: usize, width: usize) {
+		let msg = self.0.get(PART_MSG);
+		let body = msg.strip_suffix(b"\n").unwrap_or(msg);
+		let total =
+			if body.is_empty() { 0 }
+			else { body.split(|&b| b == b'\n').count() };
+		if total <= max { return; }
+
+		let mut new = Vec::with_capacity(msg.len());
+		let mut lead: &[u8] = &[];
+		for line in body.split(|&b| b == b'\n').take(max) {
+			new.extend_from_slice(line);
+			new.push(b'\n');
+			lead = &line[..line.iter().take_while(|b| matches!(b, b' ' | b'\t')).count()];
+		}
+
+		let dropped = total - max;
+		let mut note = Vec::with_capacity(32);
+		note.extend_from_slice("…(".as_bytes());
+		note.extend_from_slice(NiceU64::from(u64::saturating_from(dropped)).as_bytes());
+		note.extend_from_slice(
+			if dropped == 1 { b" more line)" }
+			else { b" more lines)" }
+		);
+
+		#[cfg(feature = "fitted")]
+		{
+			// If nothing is kept, the note shares the line with the prefix.
+			let before =
+				if max == 0 { crate::width(&self.0[..self.0.start(PART_MSG) as usize]) }
+				else { 0 };
+
+			// The count matters more than the alignment, so drop the lead
+			// first, then trim the note itself if it still doesn't fit.
+			let mut avail = width.saturating_sub(before);
+			if avail < crate::width(lead) + crate::width(&note) { lead = &[]; }
+			else { avail -= crate::width(lead); }
+			note.truncate(crate::length_width(&note, avail));
+		}
+
+		new.extend_from_slice(lead);
+		if ! note.is_empty() {
+			new.extend_from_slice(b"\x1b[2m");
+			new.extend_from_slice(&note);
+			new.extend_from_slice(b"\x1b[0m");
+		}
+		self.0.replace(PART_MSG, &new);
+	}
+
 	#[cfg(feature = "timestamps")]
 	#[cfg_attr(docsrs, doc(cfg(feature = "timestamps")))]
 	/// # Set Timestamp.
@@ -746,8 +1491,25 @@ impl Msg {
 			buf.extend_from_slice(color.as_bytes());
 			buf.push(b'm');
 			buf.extend_from_slice(prefix);
-			buf.extend_from_slice(b":\x1b[0m ");
+			buf.extend_from_slice(Self::SEPARATOR_ANSI.as_bytes());
+
+			self.0.replace(PART_PREFIX, buf.as_slice());
+		}
+	}
+
+	/// # Set Badge Prefix.
+	///
+	/// This is the setter companion to the [`Msg::with_badge_prefix`] builder
+	/// method. Refer to that documentation for more information.
+	pub fn set_badge_prefix<S>(&mut self, prefix: S, color: u8)
+	where S: AsRef<str> {
+		let badge = MsgBadge(prefix.as_ref(), color);
+		let len = badge.prefix_len();
 
+		if len == 0 { self.0.truncate(PART_PREFIX, 0); }
+		else {
+			let mut buf = Vec::with_capacity(len);
+			badge.prefix_push(&mut buf);
 			self.0.replace(PART_PREFIX, buf.as_slice());
 		}
 	}
@@ -760,6 +1522,111 @@ impl Msg {
 	pub fn set_msg<S>(&mut self, msg: S)
 	where S: AsRef<str> {
 		self.0.replace(PART_MSG, msg.as_ref().as_bytes());
+		self.1 = 0;
+	}
+
+	#[inline]
+	/// # Set Message (Bytes).
+	///
+	/// Same as [`Msg::set_msg`], but for raw bytes — read from a file or
+	/// socket, for example — which are validated as UTF-8 first.
+	///
+	/// See also [`Msg::set_msg_bytes_lossy`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::Msg;
+	///
+	/// let mut msg = Msg::plain("Hello");
+	/// assert!(msg.set_msg_bytes(b"Goodbye").is_ok());
+	/// assert_eq!(msg.message(), "Goodbye");
+	///
+	/// // Invalid UTF-8 is rejected, leaving the original unchanged.
+	/// assert!(msg.set_msg_bytes(b"Bad \xFF").is_err());
+	/// assert_eq!(msg.message(), "Goodbye");
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// If the bytes are not valid UTF-8, an error is returned and the message
+	/// is left as-is.
+	pub fn set_msg_bytes(&mut self, msg: &[u8]) -> Result<(), Utf8Error> {
+		std::str::from_utf8(msg).map(|msg| self.set_msg(msg))
+	}
+
+	#[inline]
+	/// # Set Message (Bytes, Lossy).
+	///
+	/// Same as [`Msg::set_msg_bytes`], except invalid UTF-8 sequences are
+	/// replaced with `U+FFFD REPLACEMENT CHARACTER` rather than rejected.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::Msg;
+	///
+	/// let mut msg = Msg::plain("Hello");
+	/// msg.set_msg_bytes_lossy(b"Bad \xFF");
+	/// assert_eq!(msg.message(), "Bad \u{FFFD}");
+	/// ```
+	pub fn set_msg_bytes_lossy(&mut self, msg: &[u8]) {
+		self.set_msg(String::from_utf8_lossy(msg));
+	}
+
+	/// # Set Link.
+	///
+	/// This is the setter companion to the [`Msg::with_link`] builder method.
+	/// Refer to that documentation for more information.
+	pub fn set_link(&mut self, url: &str) {
+		let url = url.trim();
+		if url.is_empty() || url.bytes().any(|b| b.is_ascii_control()) { return; }
+
+		let old = self.0.get(PART_MSG);
+		if old.is_empty() { return; }
+
+		let mut new = Vec::with_capacity(old.len() + url.len() + 12);
+		new.extend_from_slice(b"\x1b]8;;");
+		new.extend_from_slice(url.as_bytes());
+		new.extend_from_slice(b"\x1b\\");
+		new.extend_from_slice(old);
+		new.extend_from_slice(b"\x1b]8;;\x1b\\");
+		self.0.replace(PART_MSG, &new);
+	}
+
+	/// # Set Section Markers.
+	///
+	/// This is the setter companion to the [`Msg::with_section_markers`]
+	/// builder method. Refer to that documentation for more information.
+	pub fn set_section_markers(&mut self) {
+		/// # Section Start.
+		const START: &[u8] = b"\x1b]133;A\x07";
+
+		/// # Section End.
+		const END: &[u8] = b"\x1b]133;C\x07";
+
+		let old = self.0.get(PART_MSG);
+		if old.starts_with(START) && old.ends_with(END) { return; }
+
+		let mut new = Vec::with_capacity(old.len() + START.len() + END.len());
+		new.extend_from_slice(START);
+		new.extend_from_slice(old);
+		new.extend_from_slice(END);
+		self.0.replace(PART_MSG, &new);
+	}
+
+	/// # Push Quoted Argument.
+	///
+	/// This is the setter companion to the [`Msg::with_quoted_arg`] builder
+	/// method. Refer to that documentation for more information.
+	pub fn push_quoted_arg<S>(&mut self, arg: S)
+	where S: AsRef<str> {
+		let arg = sanitize(arg.as_ref());
+		let mut v = Vec::with_capacity(arg.len() + 13);
+		v.extend_from_slice(b"'\x1b[1;4m");
+		v.extend_from_slice(arg.as_bytes());
+		v.extend_from_slice(b"\x1b[0m'");
+		self.0.extend(PART_MSG, &v);
 	}
 
 	#[inline]
@@ -772,6 +1639,33 @@ impl Msg {
 		self.0.replace(PART_SUFFIX, suffix.as_ref().as_bytes());
 	}
 
+	#[inline]
+	/// # Set Suffix (Sanitized).
+	///
+	/// This is the setter companion to the [`Msg::with_sanitized_suffix`]
+	/// builder method. Refer to that documentation for more information.
+	pub fn set_sanitized_suffix<S>(&mut self, suffix: S)
+	where S: AsRef<str> {
+		self.0.replace(PART_SUFFIX, sanitize(suffix.as_ref()).as_bytes());
+	}
+
+	/// # Set Note.
+	///
+	/// This is the setter companion to the [`Msg::with_note`] builder method.
+	/// Refer to that documentation for more information.
+	pub fn set_note<S>(&mut self, note: S)
+	where S: AsRef<str> {
+		let note = note.as_ref().as_bytes();
+		if note.is_empty() { self.0.truncate(PART_SUFFIX, 0); }
+		else {
+			let mut v = Vec::with_capacity(note.len() + 11);
+			v.extend_from_slice(b" \x1b[2m(");
+			v.extend_from_slice(note);
+			v.extend_from_slice(b")\x1b[0m");
+			self.0.replace(PART_SUFFIX, &v);
+		}
+	}
+
 	/// # Strip ANSI Formatting.
 	///
 	/// Remove colors, bold, etc. from the message.
@@ -797,6 +1691,37 @@ impl Msg {
 
 		changed
 	}
+
+	#[cfg(feature = "fitted")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "fitted")))]
+	/// # Normalize.
+	///
+	/// This is the setter companion to the [`Msg::normalized`] builder
+	/// method. Refer to that documentation for more information.
+	///
+	/// Returns true if the content was modified.
+	///
+	/// **This requires the `fitted` crate feature.**
+	pub fn normalize(&mut self) -> bool {
+		use unicode_normalization::{
+			IsNormalized,
+			UnicodeNormalization,
+		};
+
+		let old = self.0.get(PART_MSG);
+		if old.is_ascii() { return false; }
+		let Ok(old) = std::str::from_utf8(old) else { return false; };
+		if matches!(unicode_normalization::is_nfc_quick(old.chars()), IsNormalized::Yes) {
+			return false;
+		}
+
+		let new: String = old.nfc().collect();
+		if new == old { false }
+		else {
+			self.0.replace(PART_MSG, new.as_bytes());
+			true
+		}
+	}
 }
 
 #[cfg(feature = "progress")]
@@ -867,6 +1792,111 @@ impl Msg {
 	/// use [`Msg::as_ref`] or [`Msg::borrow`].
 	pub fn as_str(&self) -> &str { self.0.as_str() }
 
+	#[must_use]
+	/// # As List Item.
+	///
+	/// Return the message as a string with a dimmed list `marker` — a bullet,
+	/// for example — inserted between the indentation and the rest of the
+	/// content.
+	///
+	/// The indentation is replaced with `indent` levels, exactly as with
+	/// [`Msg::with_indent`]. Everything else, including the prefix, is left
+	/// as-is.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::Msg;
+	///
+	/// let msg = Msg::plain("Apples");
+	/// assert_eq!(
+	///     msg.as_list_item("•", 1),
+	///     "    \x1b[2m•\x1b[0m Apples",
+	/// );
+	/// ```
+	pub fn as_list_item(&self, marker: &str, indent: u8) -> String {
+		let mut tmp = self.clone();
+		tmp.set_indent(indent);
+		let (lead, rest) = tmp.as_str().split_at(tmp.0.end(PART_INDENT) as usize);
+
+		let mut out = String::with_capacity(tmp.len() + marker.len() + 9);
+		out.push_str(lead);
+		if ! marker.is_empty() {
+			out.push_str("\x1b[2m");
+			out.push_str(marker);
+			out.push_str("\x1b[0m ");
+		}
+		out.push_str(rest);
+		out
+	}
+
+	#[cfg(feature = "fitted")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "fitted")))]
+	#[must_use]
+	#[inline]
+	/// # Spinner Frame.
+	///
+	/// Return the message as a self-overwriting status line — leading `\r`,
+	/// no trailing line break — prefixed with the `frame`th glyph of a
+	/// Braille spinner, like `⠋ Working…`. Bump `frame` with each print to
+	/// animate it.
+	///
+	/// This is a minimal spinner primitive for tools too small to warrant a
+	/// full [`Progless`](crate::Progless). Use [`Msg::clear_status`] (or
+	/// [`Msg::eclear_status`]) to erase the line when finished.
+	///
+	/// The line is trimmed to fit the terminal width when the
+	/// `terminal_size` dependency is enabled (as it is with the `progress`
+	/// feature), otherwise — or if the width cannot be determined — 80
+	/// columns is assumed.
+	///
+	/// **This requires the `fitted` crate feature.**
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Msg;
+	///
+	/// let msg = Msg::plain("Working…");
+	/// for frame in 0..50 {
+	///     eprint!("{}", msg.spinner_frame(frame));
+	///     // Do some work.
+	/// }
+	///
+	/// Msg::eclear_status();
+	/// ```
+	pub fn spinner_frame(&self, frame: usize) -> String {
+		self.spinner_frame__(frame, Self::rule_width())
+	}
+
+	#[cfg(feature = "fitted")]
+	/// # Spinner Frame (w/ Width).
+	///
+	/// This builds the line for `spinner_frame`.
+	fn spinner_frame__(&self, frame: usize, width: usize) -> String {
+		/// # Spinner Glyphs.
+		const FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+		// Strip the line break, if any.
+		let tmp;
+		let msg: &Self =
+			if 0 == self.0.len(PART_NEWLINE) { self }
+			else {
+				tmp = self.clone().with_newline(false);
+				&tmp
+			};
+
+		let mut out = String::with_capacity(msg.len() + 30);
+		out.push_str("\r\x1b[K\x1b[1;38;5;199m");
+		out.push(FRAMES[frame % FRAMES.len()]);
+		out.push_str("\x1b[0m ");
+
+		// Leave the last column empty to avoid auto-wrap weirdness.
+		let body = msg.fitted(width.saturating_sub(3));
+		out.push_str(std::str::from_utf8(&body).unwrap_or_default());
+		out
+	}
+
 	#[must_use]
 	#[inline]
 	/// # Into Vec.
@@ -874,6 +1904,36 @@ impl Msg {
 	/// Consume the message, returning an owned `Vec<u8>`.
 	pub fn into_vec(self) -> Vec<u8> { self.0.into_vec() }
 
+	#[must_use]
+	/// # Into Vec (With Newline).
+	///
+	/// Same as [`Msg::into_vec`], except a trailing line break is added if the
+	/// message doesn't already end with one, regardless of whether or not
+	/// [`Msg::with_newline`] was set.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::Msg;
+	///
+	/// // No double newlines…
+	/// assert_eq!(
+	///     Msg::plain("Hello").with_newline(true).into_vec_with_newline(),
+	///     b"Hello\n",
+	/// );
+	///
+	/// // …and no missing ones either.
+	/// assert_eq!(
+	///     Msg::plain("Hello").into_vec_with_newline(),
+	///     b"Hello\n",
+	/// );
+	/// ```
+	pub fn into_vec_with_newline(self) -> Vec<u8> {
+		let mut out = self.0.into_vec();
+		if ! out.ends_with(b"\n") { out.push(b'\n'); }
+		out
+	}
+
 	#[must_use]
 	#[inline]
 	/// # Into String.
@@ -885,8 +1945,8 @@ impl Msg {
 
 	#[cfg(feature = "fitted")]
 	#[cfg_attr(docsrs, doc(cfg(feature = "fitted")))]
-	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
 	#[must_use]
+	#[inline]
 	/// # Capped Width Slice.
 	///
 	/// This will return a byte string that should fit a given console width if
@@ -898,38 +1958,69 @@ impl Msg {
 	/// space. Prefixes, suffixes, the trailing newline, etc., are left
 	/// unchanged.
 	///
+	/// Tabs, like other control characters, are treated as having no width.
+	/// Use [`Msg::fitted_with_tab_stop`] to expand them instead.
+	///
 	/// If the message cannot be made to fit, an empty byte string is returned.
 	///
 	/// **This requires the `fitted` crate feature.**
-	pub fn fitted(&self, width: usize) -> Cow<[u8]> {
+	pub fn fitted(&self, width: usize) -> Cow<'_, [u8]> {
+		self.fitted_with_tab_stop(width, 0)
+	}
+
+	#[cfg(feature = "fitted")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "fitted")))]
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	#[must_use]
+	/// # Capped Width Slice (Custom Tab Stop).
+	///
+	/// Same as [`Msg::fitted`], but with tabs expanded to the next multiple of
+	/// `tab_stop` columns, relative to the start of the line, e.g.
+	/// [`TAB_STOP`](crate::TAB_STOP). Passing `0` is equivalent to
+	/// [`Msg::fitted`].
+	///
+	/// **This requires the `fitted` crate feature.**
+	pub fn fitted_with_tab_stop(&self, width: usize, tab_stop: usize)
+	-> Cow<'_, [u8]> {
 		// Quick length bypass; length will only ever be greater or equal to
-		// width, so if that fits, the message fits.
-		if self.len() <= width {
+		// width (tabs aside), so if that fits, the message fits.
+		if self.len() <= width && (tab_stop == 0 || ! self.0.contains(&b'\t')) {
 			return Cow::Borrowed(self);
 		}
 
-		// If the fixed width bits are themselves too big, we can't fit print.
+		// Figure out the column the message starts at.
 		#[cfg(feature = "timestamps")]
-		let fixed_width: usize =
+		let lead: usize =
 			self.0.len(PART_INDENT) as usize +
-			crate::width(self.0.get(PART_PREFIX)) +
-			crate::width(self.0.get(PART_SUFFIX)) +
 			if 0 == self.0.len(PART_TIMESTAMP) { 0 }
 			else { 21 };
 
 		#[cfg(not(feature = "timestamps"))]
-		let fixed_width: usize =
-			self.0.len(PART_INDENT) as usize +
-			crate::width(self.0.get(PART_PREFIX)) +
-			crate::width(self.0.get(PART_SUFFIX));
+		let lead: usize = self.0.len(PART_INDENT) as usize;
+
+		let lead = lead + crate::fitted::width_from(
+			self.0.get(PART_PREFIX),
+			lead,
+			tab_stop,
+		);
 
+		// If the fixed width bits are themselves too big, we can't fit print.
+		let fixed_width = lead + crate::width_with_tab_stop(
+			self.0.get(PART_SUFFIX),
+			tab_stop,
+		);
 		if fixed_width > width {
 			return Cow::Borrowed(&[]);
 		}
 
 		// Check the length again; the fixed bits might just have a lot of
 		// ANSI.
-		let keep = crate::length_width(self.0.get(PART_MSG), width - fixed_width) as u32;
+		let keep = crate::fitted::length_width_from(
+			self.0.get(PART_MSG),
+			width - fixed_width,
+			lead,
+			tab_stop,
+		) as u32;
 		if keep == 0 { Cow::Borrowed(&[]) }
 		else if keep == self.0.len(PART_MSG) { Cow::Borrowed(self) }
 		else {
@@ -941,25 +2032,173 @@ impl Msg {
 			// unnecessary, but nitpicking is more expensive than redundancy
 			// here.
 			if tmp.0.get(PART_MSG).contains(&b'\x1b') {
-				tmp.0.extend(PART_MSG, b"\x1b[0m");
+				tmp.0.extend(PART_MSG, Self::RESET.as_bytes());
 			}
 
 			Cow::Owned(tmp.into_vec())
 		}
 	}
-}
 
-/// ## Details.
-impl Msg {
+	#[cfg(feature = "fitted")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "fitted")))]
 	#[must_use]
 	#[inline]
-	/// # Length.
+	/// # With Right-Aligned Status.
 	///
-	/// This returns the total length of the entire `Msg`, ANSI markup and all.
-	pub const fn len(&self) -> usize {
-		// Because the buffers used by `Msg` end on partitioned space, the end
-		// of the last part is equal to the total length. Let's use that method
-		// since it is constant!
+	/// Return the message as a string exactly `total_width` columns wide,
+	/// with `status` hugging the right edge and a dotted leader filling the
+	/// space in between, i.e. `"Task name ........ [OK]"`.
+	///
+	/// If the message and status don't both fit, the message is trimmed, as
+	/// with [`Msg::fitted`]. (If the status doesn't fit by itself, it is
+	/// returned as-is.)
+	///
+	/// The trailing line break, if any, is preserved.
+	///
+	/// Use [`Msg::with_right_status_and_leader`] for a different leader.
+	///
+	/// **This requires the `fitted` crate feature.**
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::Msg;
+	///
+	/// let msg = Msg::plain("Task name");
+	/// assert_eq!(msg.with_right_status("[OK]", 20), "Task name ..... [OK]");
+	/// ```
+	pub fn with_right_status(&self, status: &str, total_width: usize) -> String {
+		self.with_right_status_and_leader(status, total_width, '.')
+	}
+
+	#[cfg(feature = "fitted")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "fitted")))]
+	#[must_use]
+	/// # With Right-Aligned Status (Custom Leader).
+	///
+	/// Same as [`Msg::with_right_status`], but with a custom leader character
+	/// — a space, for example — to fill the gap. The leader should occupy a
+	/// single column.
+	///
+	/// **This requires the `fitted` crate feature.**
+	pub fn with_right_status_and_leader(
+		&self,
+		status: &str,
+		total_width: usize,
+		leader: char,
+	) -> String {
+		let newline = 0 != self.0.len(PART_NEWLINE);
+		let status_width = crate::width(status.as_bytes());
+
+		let mut out = String::new();
+		if status_width < total_width {
+			// Fit the message, leaving at least one column for the gap.
+			let tmp;
+			let msg: &Self =
+				if newline {
+					tmp = self.clone().with_newline(false);
+					&tmp
+				}
+				else { self };
+			let line = msg.fitted(total_width - status_width - 1);
+			out.push_str(std::str::from_utf8(&line).unwrap_or_default());
+
+			// Fill the gap, padding the leader with a space on either side
+			// if there's room.
+			let gap = total_width - status_width - crate::width(&line);
+			if leader == ' ' || gap < 3 {
+				out.extend(std::iter::repeat_n(' ', gap));
+			}
+			else {
+				out.push(' ');
+				out.extend(std::iter::repeat_n(leader, gap - 2));
+				out.push(' ');
+			}
+		}
+
+		out.push_str(status);
+		if newline { out.push('\n'); }
+		out
+	}
+
+	#[cfg(feature = "fitted")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "fitted")))]
+	#[must_use]
+	/// # Line Count.
+	///
+	/// Return the number of terminal lines the message will occupy when
+	/// printed to a screen `width` columns wide, accounting for wrapping,
+	/// ANSI sequences, wide characters, and embedded line breaks.
+	///
+	/// The trailing line break, if any, does not count as an extra line.
+	///
+	/// If `width` is zero, wrapping is ignored and only the hard line breaks
+	/// are considered.
+	///
+	/// **This requires the `fitted` crate feature.**
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::Msg;
+	///
+	/// let msg = Msg::plain("Hello World").with_newline(true);
+	/// assert_eq!(msg.line_count(80), 1);
+	/// assert_eq!(msg.line_count(5), 3);
+	///
+	/// let msg = Msg::plain("Hello\nWorld");
+	/// assert_eq!(msg.line_count(80), 2);
+	/// ```
+	pub fn line_count(&self, width: usize) -> usize {
+		let bytes: &[u8] = &self.0;
+		let bytes = bytes.strip_suffix(b"\n").unwrap_or(bytes);
+		if bytes.is_empty() { return 0; }
+
+		bytes.split(|b| b'\n'.eq(b))
+			.map(|mut line| {
+				let mut count = 1;
+				if width != 0 {
+					loop {
+						let mut len = crate::length_width(line, width);
+						if crate::width(&line[..len]) == 0 {
+							// Nothing left to show?
+							if crate::width(line) == 0 { break; }
+
+							// Otherwise the next character is wider than the
+							// screen. It can't be split, so will take up a
+							// line all its own.
+							let mut stop = width;
+							while crate::width(&line[..len]) == 0 {
+								stop += 1;
+								len = crate::length_width(line, stop);
+							}
+						}
+						if line.len() <= len { break; }
+
+						// Only count the remainder if it has some width; it
+						// might just be ANSI.
+						line = &line[len..];
+						if crate::width(line) == 0 { break; }
+						count += 1;
+					}
+				}
+				count
+			})
+			.sum()
+	}
+}
+
+/// ## Details.
+impl Msg {
+	#[must_use]
+	#[inline]
+	/// # Length.
+	///
+	/// This returns the total length of the entire `Msg`, ANSI markup and all.
+	pub const fn len(&self) -> usize {
+		// Because the buffers used by `Msg` end on partitioned space, the end
+		// of the last part is equal to the total length. Let's use that method
+		// since it is constant!
 		self.0.end(PART_NEWLINE) as usize
 	}
 
@@ -967,6 +2206,131 @@ impl Msg {
 	#[inline]
 	/// # Is Empty.
 	pub const fn is_empty(&self) -> bool { self.len() == 0 }
+
+	#[must_use]
+	#[inline]
+	/// # Prefix.
+	///
+	/// Return the prefix part of the message — ANSI formatting, trailing
+	/// space, and all — or an empty string if there is none.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::Msg;
+	///
+	/// let msg = Msg::error("Oh no!");
+	/// assert_eq!(msg.prefix(), "\x1b[91;1mError:\x1b[0m ");
+	/// ```
+	pub fn prefix(&self) -> &str {
+		std::str::from_utf8(self.0.get(PART_PREFIX)).unwrap_or_default()
+	}
+
+	#[must_use]
+	#[inline]
+	/// # Message.
+	///
+	/// Return the message part of the message, i.e. the body, without any
+	/// indentation, timestamp, prefix, suffix, or trailing line break.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::Msg;
+	///
+	/// let msg = Msg::error("Oh no!");
+	/// assert_eq!(msg.message(), "Oh no!");
+	/// ```
+	pub fn message(&self) -> &str {
+		std::str::from_utf8(self.0.get(PART_MSG)).unwrap_or_default()
+	}
+
+	#[must_use]
+	#[inline]
+	/// # Suffix.
+	///
+	/// Return the suffix part of the message, or an empty string if there is
+	/// none.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::Msg;
+	///
+	/// let msg = Msg::error("Oh no!").with_suffix(" (Again.)");
+	/// assert_eq!(msg.suffix(), " (Again.)");
+	/// ```
+	pub fn suffix(&self) -> &str {
+		std::str::from_utf8(self.0.get(PART_SUFFIX)).unwrap_or_default()
+	}
+
+	#[must_use]
+	/// # Content Hash.
+	///
+	/// Return a hash of the message's visible content — prefix, message, and
+	/// suffix — useful for spotting repeats, e.g. to collapse them into a
+	/// "last message repeated N times" line.
+	///
+	/// Unlike the [`Hash`](std::hash::Hash) implementation, this ignores ANSI
+	/// formatting, indentation, timestamps, and the trailing line break, so
+	/// messages differing only in those respects hash the same.
+	///
+	/// A fast, fixed-seed hasher is used, so the same content always produces
+	/// the same hash, across runs and Rust releases alike. (The algorithm
+	/// could still change in a future version of this crate, so hashes should
+	/// not be persisted.)
+	///
+	/// Note: the hash is _not_ precomputed or cached; it is calculated from
+	/// scratch on each call, so hold onto the value if you need it more than
+	/// once.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use fyi_msg::Msg;
+	///
+	/// let a = Msg::plain("\x1b[1mHello\x1b[0m World").with_newline(true);
+	/// let b = Msg::plain("Hello World").with_indent(1);
+	/// assert_ne!(a, b);
+	/// assert_eq!(a.content_hash(), b.content_hash());
+	/// ```
+	pub fn content_hash(&self) -> u64 {
+		use std::hash::Hasher;
+
+		/// # Hash Visible Spans.
+		///
+		/// Feed the ANSI-free stretches of `src` to the hasher, a slice at a
+		/// time.
+		fn write_visible(h: &mut ContentHasher, src: &[u8]) {
+			// Nothing to strip.
+			if ! src.contains(&b'\x1b') {
+				h.write(src);
+				return;
+			}
+
+			let mut iter = NoAnsi::<u8, _>::new(src.iter().copied());
+			let mut start = 0;
+			let mut end = 0;
+			while iter.next().is_some() {
+				// Each time we skip over a sequence, flush the previous span.
+				let pos = iter.byte_pos() - 1;
+				if pos != end {
+					if start != end { h.write(&src[start..end]); }
+					start = pos;
+				}
+				end = pos + 1;
+			}
+			if start != end { h.write(&src[start..end]); }
+		}
+
+		let mut h = ContentHasher::default();
+		write_visible(&mut h, self.0.get(PART_PREFIX));
+		h.write_u8(0xFF); // Part boundary; can't appear in valid UTF-8.
+		write_visible(&mut h, self.0.get(PART_MSG));
+		h.write_u8(0xFF);
+		write_visible(&mut h, self.0.get(PART_SUFFIX));
+		h.finish()
+	}
 }
 
 /// ## Printing.
@@ -986,13 +2350,7 @@ impl Msg {
 	/// use fyi_msg::Msg;
 	/// Msg::plain("Hello world!").with_newline(true).print();
 	/// ```
-	pub fn print(&self) {
-		use io::Write;
-
-		let writer = io::stdout();
-		let mut handle = writer.lock();
-		let _res = handle.write_all(&self.0).and_then(|()| handle.flush());
-	}
+	pub fn print(&self) { let _res = self.try_print(); }
 
 	#[inline]
 	/// # Locked Print to `STDERR`.
@@ -1009,12 +2367,447 @@ impl Msg {
 	/// use fyi_msg::Msg;
 	/// Msg::error("Oh no!").with_newline(true).eprint();
 	/// ```
-	pub fn eprint(&self) {
+	pub fn eprint(&self) { let _res = self.try_eprint(); }
+
+	#[inline]
+	/// # Locked Print to `STDOUT` (Fallible).
+	///
+	/// Same as [`Msg::print`], except any write errors are passed back to the
+	/// caller instead of being silently ignored.
+	///
+	/// This can be useful for e.g. detecting `ErrorKind::BrokenPipe` when
+	/// output is piped to a program like `head` that might close the pipe
+	/// early.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Msg;
+	/// use std::io::ErrorKind;
+	///
+	/// if let Err(e) = Msg::plain("Hello world!").with_newline(true).try_print() {
+	///     if e.kind() == ErrorKind::BrokenPipe { std::process::exit(0); }
+	/// }
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// Any errors encountered while writing or flushing `STDOUT` are bubbled
+	/// up.
+	pub fn try_print(&self) -> io::Result<()> {
+		use io::Write;
+
+		let writer = io::stdout();
+		let mut handle = writer.lock();
+		handle.write_all(&self.0).and_then(|()| handle.flush())
+	}
+
+	#[inline]
+	/// # Locked Print to `STDERR` (Fallible).
+	///
+	/// Same as [`Msg::eprint`], except any write errors are passed back to the
+	/// caller instead of being silently ignored.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Msg;
+	///
+	/// Msg::error("Oh no!").try_eprint().expect("Unable to print to STDERR.");
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// Any errors encountered while writing or flushing `STDERR` are bubbled
+	/// up.
+	pub fn try_eprint(&self) -> io::Result<()> {
 		use io::Write;
 
 		let writer = io::stderr();
 		let mut handle = writer.lock();
-		let _res = handle.write_all(&self.0).and_then(|()| handle.flush());
+		handle.write_all(&self.0).and_then(|()| handle.flush())
+	}
+
+	#[inline]
+	/// # Print to `STDOUT` If Severe Enough.
+	///
+	/// Same as [`Msg::print`], but only if the message's kind ranks at or
+	/// above `min` per [`MsgKind::severity`]. This makes `--quiet`/`--verbose`
+	/// gating a one-liner.
+	///
+	/// The kind is inferred from the (unaltered) built-in prefix. Messages
+	/// without one — plain text, custom prefixes, etc. — are always printed.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::{Msg, MsgKind};
+	///
+	/// let min = MsgKind::Warning;
+	/// Msg::debug("Nobody will see this.").with_newline(true).print_if(min);
+	/// Msg::error("Everybody will see this.").with_newline(true).print_if(min);
+	/// Msg::plain("And this too.").with_newline(true).print_if(min);
+	/// ```
+	pub fn print_if(&self, min: MsgKind) {
+		if self.meets_severity(min) { self.print(); }
+	}
+
+	#[inline]
+	/// # Print to `STDERR` If Severe Enough.
+	///
+	/// Same as [`Msg::print_if`], but for `STDERR`.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::{Msg, MsgKind};
+	///
+	/// Msg::warning("Careful!").with_newline(true).eprint_if(MsgKind::Info);
+	/// ```
+	pub fn eprint_if(&self, min: MsgKind) {
+		if self.meets_severity(min) { self.eprint(); }
+	}
+
+	/// # Meets Severity?
+	///
+	/// Returns `true` if the message's (built-in) kind ranks at or above
+	/// `min`, or has no recognizable kind at all.
+	fn meets_severity(&self, min: MsgKind) -> bool {
+		self.builtin_kind().is_none_or(|k| min.severity() <= k.severity())
+	}
+
+	/// # Built-In Kind.
+	///
+	/// Infer the kind from the prefix, if it is an unaltered built-in one.
+	fn builtin_kind(&self) -> Option<MsgKind> {
+		let prefix = self.0.get(PART_PREFIX);
+		[
+			MsgKind::Confirm,
+			MsgKind::Crunched,
+			MsgKind::Debug,
+			MsgKind::Done,
+			MsgKind::Error,
+			MsgKind::Info,
+			MsgKind::Notice,
+			MsgKind::Review,
+			MsgKind::Skipped,
+			MsgKind::Success,
+			MsgKind::Task,
+			MsgKind::Warning,
+		]
+			.into_iter()
+			.find(|k| k.as_bytes() == prefix)
+	}
+
+	#[inline]
+	/// # Locked Print All to `STDOUT`.
+	///
+	/// Print a batch of messages to `STDOUT` under a single lock, with a
+	/// single flush at the end.
+	///
+	/// This is cheaper than calling [`Msg::print`] for each one, and ensures
+	/// the batch won't be interleaved with output from other threads.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Msg;
+	///
+	/// Msg::print_all(&[
+	///     Msg::warning("Line 5 is too long.").with_newline(true),
+	///     Msg::warning("Line 9 is too long.").with_newline(true),
+	///     Msg::error("Validation failed.").with_newline(true),
+	/// ]);
+	/// ```
+	pub fn print_all(msgs: &[Self]) {
+		let writer = io::stdout();
+		let mut handle = writer.lock();
+		let _res = Self::write_all__(&mut handle, msgs);
+	}
+
+	#[inline]
+	/// # Locked Print All to `STDERR`.
+	///
+	/// Same as [`Msg::print_all`], but printed to `STDERR` instead of
+	/// `STDOUT`.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Msg;
+	///
+	/// Msg::eprint_all(&[
+	///     Msg::error("Line 5 is too long.").with_newline(true),
+	///     Msg::error("Line 9 is too long.").with_newline(true),
+	/// ]);
+	/// ```
+	pub fn eprint_all(msgs: &[Self]) {
+		let writer = io::stderr();
+		let mut handle = writer.lock();
+		let _res = Self::write_all__(&mut handle, msgs);
+	}
+
+	#[inline]
+	/// # Print Status Line to `STDOUT`.
+	///
+	/// Print the message in place of the current line — rewinding and
+	/// clearing it first — without a trailing line break, so that subsequent
+	/// calls overwrite it.
+	///
+	/// This provides a lightweight self-updating status line for cases too
+	/// simple to warrant a full [`Progless`](crate::Progless).
+	///
+	/// When the `progress` crate feature is enabled (or both `fitted` and
+	/// `terminal_size`), the line is trimmed to fit the terminal to prevent
+	/// wrapping from mucking things up.
+	///
+	/// Use [`Msg::clear_status`] to erase the line when finished.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Msg;
+	///
+	/// for i in 0..10 {
+	///     Msg::plain(format!("Working on item #{i}…")).print_status();
+	///     // Do some work.
+	/// }
+	///
+	/// Msg::clear_status();
+	/// ```
+	pub fn print_status(&self) {
+		let writer = io::stdout();
+		#[cfg(all(feature = "fitted", feature = "terminal_size"))]
+		let width = terminal_size::terminal_size_of(&writer).map(|(w, _)| usize::from(w.0));
+		let mut handle = writer.lock();
+
+		#[cfg(all(feature = "fitted", feature = "terminal_size"))]
+		let _res = self.write_status__(&mut handle, width);
+		#[cfg(not(all(feature = "fitted", feature = "terminal_size")))]
+		let _res = self.write_status__(&mut handle);
+	}
+
+	#[inline]
+	/// # Print Status Line to `STDERR`.
+	///
+	/// Same as [`Msg::print_status`], but printed to `STDERR` instead of
+	/// `STDOUT`.
+	///
+	/// Use [`Msg::eclear_status`] to erase the line when finished.
+	pub fn eprint_status(&self) {
+		let writer = io::stderr();
+		#[cfg(all(feature = "fitted", feature = "terminal_size"))]
+		let width = terminal_size::terminal_size_of(&writer).map(|(w, _)| usize::from(w.0));
+		let mut handle = writer.lock();
+
+		#[cfg(all(feature = "fitted", feature = "terminal_size"))]
+		let _res = self.write_status__(&mut handle, width);
+		#[cfg(not(all(feature = "fitted", feature = "terminal_size")))]
+		let _res = self.write_status__(&mut handle);
+	}
+
+	#[inline]
+	/// # Clear Status Line from `STDOUT`.
+	///
+	/// Erase the current line, e.g. one printed with [`Msg::print_status`].
+	pub fn clear_status() {
+		use io::Write;
+
+		let writer = io::stdout();
+		let mut handle = writer.lock();
+		let _res = handle.write_all(b"\r\x1b[K").and_then(|()| handle.flush());
+	}
+
+	#[inline]
+	/// # Clear Status Line from `STDERR`.
+	///
+	/// Erase the current line, e.g. one printed with [`Msg::eprint_status`].
+	pub fn eclear_status() {
+		use io::Write;
+
+		let writer = io::stderr();
+		let mut handle = writer.lock();
+		let _res = handle.write_all(b"\r\x1b[K").and_then(|()| handle.flush());
+	}
+
+	/// # Write Status Line.
+	///
+	/// Rewind and clear the current line, then write the message sans line
+	/// break, trimmed to fit the width (if known).
+	fn write_status__<W: io::Write>(
+		&self,
+		handle: &mut W,
+		#[cfg(all(feature = "fitted", feature = "terminal_size"))]
+		width: Option<usize>,
+	) -> io::Result<()> {
+		// Strip the line break, if any.
+		let tmp;
+		let msg: &Self =
+			if 0 == self.0.len(PART_NEWLINE) { self }
+			else {
+				tmp = self.clone().with_newline(false);
+				&tmp
+			};
+
+		handle.write_all(b"\r\x1b[K")?;
+
+		// Leave the last column empty to avoid auto-wrap weirdness.
+		#[cfg(all(feature = "fitted", feature = "terminal_size"))]
+		if let Some(width) = width {
+			handle.write_all(&msg.fitted(width.saturating_sub(1)))?;
+		}
+		else { handle.write_all(msg)?; }
+
+		#[cfg(not(all(feature = "fitted", feature = "terminal_size")))]
+		handle.write_all(msg)?;
+
+		handle.flush()
+	}
+
+	/// # Write All.
+	///
+	/// Write all of the messages to the writer in as few calls as possible,
+	/// then flush it.
+	fn write_all__<W: io::Write>(handle: &mut W, msgs: &[Self]) -> io::Result<()> {
+		use io::IoSlice;
+
+		let mut parts: Vec<IoSlice> = msgs.iter()
+			.filter(|m| ! m.is_empty())
+			.map(|m| IoSlice::new(m))
+			.collect();
+
+		// TODO: remove once `write_all_vectored` is stable.
+		let mut parts = parts.as_mut_slice();
+		while ! parts.is_empty() {
+			match handle.write_vectored(parts) {
+				Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+				Ok(n) => IoSlice::advance_slices(&mut parts, n),
+				Err(e) =>
+					if e.kind() == io::ErrorKind::Interrupted {} // Keep trying.
+					else { return Err(e); },
+			}
+		}
+
+		handle.flush()
+	}
+
+	#[cfg(feature = "termcolor")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "termcolor")))]
+	/// # Write to `WriteColor`.
+	///
+	/// Write the message to any [`termcolor::WriteColor`] implementation,
+	/// allowing `Msg` output to be routed through an existing
+	/// `termcolor`-based color-management layer.
+	///
+	/// If the writer supports color and takes it in-band — i.e. it is not
+	/// [synchronous](termcolor::WriteColor::is_synchronous), like the legacy
+	/// Windows console — the message is written as-is, embedded ANSI
+	/// sequences and all. Otherwise the ANSI formatting is stripped first,
+	/// since such writers would print the raw escapes instead of honoring
+	/// them.
+	///
+	/// **This requires the `termcolor` crate feature.**
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Msg;
+	/// use termcolor::{ColorChoice, StandardStream};
+	///
+	/// let mut stream = StandardStream::stdout(ColorChoice::Auto);
+	/// Msg::success("Hello world!").write_color(&mut stream).unwrap();
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// Any errors encountered while writing or flushing the stream are
+	/// bubbled up.
+	pub fn write_color<W>(&self, writer: &mut W) -> io::Result<()>
+	where W: termcolor::WriteColor {
+		if
+			! self.0.contains(&b'\x1b') ||
+			(writer.supports_color() && ! writer.is_synchronous())
+		{
+			writer.write_all(&self.0)?;
+		}
+		else {
+			let stripped: Vec<u8> = NoAnsi::<u8, _>::new(self.0.iter().copied()).collect();
+			writer.write_all(&stripped)?;
+		}
+
+		writer.flush()
+	}
+
+	#[cfg(all(unix, feature = "syslog"))]
+	#[cfg_attr(docsrs, doc(cfg(all(unix, feature = "syslog"))))]
+	#[expect(unsafe_code, reason = "For libc::syslog.")]
+	/// # Send to Syslog.
+	///
+	/// Send the message to the system log, mapping the built-in kinds to the
+	/// corresponding syslog priorities:
+	///
+	/// | Kind | Priority |
+	/// | ---- | -------- |
+	/// | [`MsgKind::Error`] | `LOG_ERR` |
+	/// | [`MsgKind::Warning`] | `LOG_WARNING` |
+	/// | [`MsgKind::Notice`] | `LOG_NOTICE` |
+	/// | [`MsgKind::Debug`] | `LOG_DEBUG` |
+	/// | Everything else | `LOG_INFO` |
+	///
+	/// Only the ANSI-stripped body — message and suffix — is sent for the
+	/// built-ins, since the priority already says as much as the prefix would.
+	/// Custom prefixes are kept. Indentation, timestamps, and line breaks are
+	/// always left off; syslog has its own ideas about those.
+	///
+	/// The default facility and identity are used unless the program has
+	/// called `openlog` itself.
+	///
+	/// **This requires the `syslog` crate feature, and is only available on
+	/// Unix platforms.**
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Msg;
+	///
+	/// let msg = Msg::warning("Disk space is running low.").with_newline(true);
+	/// msg.eprint();  // For the humans.
+	/// msg.syslog();  // For the logs.
+	/// ```
+	pub fn syslog(&self) {
+		let priority = match self.builtin_kind() {
+			Some(MsgKind::Error) => libc::LOG_ERR,
+			Some(MsgKind::Warning) => libc::LOG_WARNING,
+			Some(MsgKind::Notice) => libc::LOG_NOTICE,
+			Some(MsgKind::Debug) => libc::LOG_DEBUG,
+			_ => libc::LOG_INFO,
+		};
+
+		// NULs were stripped, so this can't fail.
+		let Ok(body) = std::ffi::CString::new(self.syslog_body()) else { return; };
+
+		// Safety: both pointers are valid NUL-terminated strings, and the
+		// format consumes exactly the one argument given.
+		unsafe { libc::syslog(priority, c"%s".as_ptr(), body.as_ptr()); }
+	}
+
+	#[cfg(all(unix, feature = "syslog"))]
+	/// # Syslog Body.
+	///
+	/// Return the ANSI- and NUL-free text to send to syslog.
+	fn syslog_body(&self) -> Vec<u8> {
+		let prefix: &[u8] =
+			if self.builtin_kind().is_some() { &[] }
+			else { self.0.get(PART_PREFIX) };
+
+		NoAnsi::<u8, _>::new(
+			prefix.iter()
+				.chain(self.0.get(PART_MSG))
+				.chain(self.0.get(PART_SUFFIX))
+				.copied()
+		)
+			.filter(|&b| b != 0)
+			.collect()
 	}
 
 	#[inline]
@@ -1047,15 +2840,96 @@ impl Msg {
 	/// "N" to proceed. Positive values return `true`, negative values return
 	/// `false`. The default (if the user just hits &lt;enter&gt;) is "N".
 	///
-	/// Note: the prompt normalizes the suffix and newline parts for display.
-	/// If your message contains these parts, they will be ignored by the
-	/// prompt action, but will be retained in the original struct should you
-	/// wish to use it in some other manner later in your code.
+	/// Note: the prompt normalizes the suffix and newline parts for display.
+	/// If your message contains these parts, they will be ignored by the
+	/// prompt action, but will be retained in the original struct should you
+	/// wish to use it in some other manner later in your code.
+	///
+	/// Every example in the docs shows this in combination with the built-in
+	/// [`MsgKind::Confirm`] prefix, but this can be called on any [`Msg`]
+	/// object. The main thing worth noting is the suffix portion is
+	/// overridden for display, so don't bother putting anything there.
+	///
+	/// ## Example
+	///
+	/// ```no_run
+	/// use fyi_msg::{confirm, Msg, MsgKind};
+	///
+	/// // The manual way:
+	/// if Msg::new(MsgKind::Confirm, "Do you like chickens?").prompt() {
+	///     println!("That's great! They like you too!");
+	/// }
+	///
+	/// // The macro way:
+	/// if confirm!("Do you like chickens?") {
+	///     println!("That's great! They like you too!");
+	/// }
+	/// ```
+	pub fn prompt(&self) -> bool { self.prompt_with_default(false) }
+
+	#[must_use]
+	#[inline]
+	/// # Prompt (w/ Default).
+	///
+	/// This is identical to [`Msg::prompt`], except you specify the default
+	/// return value — `true` for Yes, `false` for No — that is returned when
+	/// the user just hits `<ENTER>`.
+	pub fn prompt_with_default(&self, default: bool) -> bool {
+		self.prompt__(default, false, None, false)
+	}
+
+	#[must_use]
+	#[inline]
+	/// # Prompt (w/ Default and Timeout).
+	///
+	/// This is identical to [`Msg::prompt_with_default`], except the default
+	/// is also returned if the user fails to answer within `timeout`. This
+	/// keeps (accidental) prompts in unattended contexts like CI from hanging
+	/// forever.
+	///
+	/// Note: Rust provides no way to interrupt a blocking read from STDIN, so
+	/// the first timed prompt starts a background reader that lives for the
+	/// rest of the program. All subsequent prompts — timed or not — share it,
+	/// so no answers are lost between them, but if your own code reads from
+	/// STDIN directly afterward, the reader may get to a line first. (Any
+	/// lines typed _ahead_ of a prompt in an interactive terminal, such as a
+	/// late answer to a prompt that already timed out, are discarded.)
+	///
+	/// ## Example
+	///
+	/// ```no_run
+	/// use fyi_msg::{confirm, Msg, MsgKind};
+	/// use std::time::Duration;
+	///
+	/// // The manual way:
+	/// if Msg::new(MsgKind::Confirm, "Do you like chickens?")
+	///     .prompt_with_timeout(true, Duration::from_secs(10))
+	/// {
+	///     println!("That's great! They like you too!");
+	/// }
+	///
+	/// // The macro way:
+	/// if confirm!(@timeout 10 yes: "Do you like chickens?") {
+	///     println!("That's great! They like you too!");
+	/// }
+	/// ```
+	pub fn prompt_with_timeout(&self, default: bool, timeout: Duration) -> bool {
+		self.prompt__(default, false, Some(timeout), false)
+	}
+
+	#[must_use]
+	#[inline]
+	/// # Prompt (w/ Default, Interactive Only).
+	///
+	/// This is identical to [`Msg::prompt_with_default`], except when STDIN
+	/// is not a terminal — piped, redirected, closed, etc. — the default is
+	/// assumed and returned immediately, without attempting to read anything.
+	///
+	/// The question and resolved answer are still printed so the choice
+	/// isn't made silently.
 	///
-	/// Every example in the docs shows this in combination with the built-in
-	/// [`MsgKind::Confirm`] prefix, but this can be called on any [`Msg`]
-	/// object. The main thing worth noting is the suffix portion is
-	/// overridden for display, so don't bother putting anything there.
+	/// Note: this is opt-in because piping answers — `yes | my-program` — is
+	/// a perfectly valid way to answer the regular prompts.
 	///
 	/// ## Example
 	///
@@ -1063,26 +2937,19 @@ impl Msg {
 	/// use fyi_msg::{confirm, Msg, MsgKind};
 	///
 	/// // The manual way:
-	/// if Msg::new(MsgKind::Confirm, "Do you like chickens?").prompt() {
+	/// if Msg::new(MsgKind::Confirm, "Do you like chickens?")
+	///     .prompt_or_default(true)
+	/// {
 	///     println!("That's great! They like you too!");
 	/// }
 	///
 	/// // The macro way:
-	/// if confirm!("Do you like chickens?") {
+	/// if confirm!(@assume_default yes: "Do you like chickens?") {
 	///     println!("That's great! They like you too!");
 	/// }
 	/// ```
-	pub fn prompt(&self) -> bool { self.prompt_with_default(false) }
-
-	#[must_use]
-	#[inline]
-	/// # Prompt (w/ Default).
-	///
-	/// This is identical to [`Msg::prompt`], except you specify the default
-	/// return value — `true` for Yes, `false` for No — that is returned when
-	/// the user just hits `<ENTER>`.
-	pub fn prompt_with_default(&self, default: bool) -> bool {
-		self.prompt__(default, false)
+	pub fn prompt_or_default(&self, default: bool) -> bool {
+		self.prompt__(default, false, None, true)
 	}
 
 	#[must_use]
@@ -1099,22 +2966,54 @@ impl Msg {
 	/// Same as [`Msg::prompt_with_default`], but printed to STDERR instead of
 	/// STDOUT.
 	pub fn eprompt_with_default(&self, default: bool) -> bool {
-		self.prompt__(default, true)
+		self.prompt__(default, true, None, false)
 	}
 
+	#[expect(clippy::significant_drop_tightening, reason = "Held for the whole prompt.")]
 	/// # Internal Prompt Handling.
 	///
-	/// This prints the prompt, handling the desired default and output.
-	fn prompt__(&self, default: bool, stderr: bool) -> bool {
+	/// This prints the prompt, handling the desired default, output, timeout
+	/// (if any), and non-interactive assumptions (if enabled).
+	fn prompt__(
+		&self,
+		default: bool,
+		stderr: bool,
+		timeout: Option<Duration>,
+		assume: bool,
+	) -> bool {
 		// Clone the message and append a little [y/N] instructional bit to the
 		// end. This might not be necessary, but preserves the original message
 		// in case it is needed again.
-		let q = self.clone()
-			.with_suffix(
-				if default { " \x1b[2m[\x1b[4mY\x1b[0;2m/n]\x1b[0m " }
-				else       { " \x1b[2m[y/\x1b[4mN\x1b[0;2m]\x1b[0m " }
-			)
-			.with_newline(false);
+		let suffix =
+			if default { " \x1b[2m[\x1b[4mY\x1b[0;2m/n]\x1b[0m " }
+			else       { " \x1b[2m[y/\x1b[4mN\x1b[0;2m]\x1b[0m " };
+		let q = self.clone().with_suffix(suffix).with_newline(false);
+
+		// If there's nobody to ask, print the assumed answer and leave it at
+		// that.
+		if assume && ! io::stdin().is_terminal() {
+			let a = q.with_suffix(format!(
+				"{suffix}\x1b[2m{}\x1b[0m",
+				if default { "y" } else { "n" },
+			))
+				.with_newline(true);
+			if stderr { a.eprint(); }
+			else { a.print(); }
+			return default;
+		}
+
+		// If there's a timeout, STDIN has to be read from a separate thread so
+		// we aren't stuck waiting on it. Once that reader exists, it has to be
+		// used for untimed prompts too or they'd be fighting over lines. The
+		// lock is held until we have our answer so concurrent prompts don't
+		// interleave.
+		let rx = timeout.map_or_else(|| STDIN_LINES.get(), |_| Some(stdin_lines()))
+			.map(|rx| rx.lock().unwrap_or_else(PoisonError::into_inner));
+
+		// Throw out anything typed before the question was even asked.
+		if let Some(rx) = rx.as_ref() {
+			if io::stdin().is_terminal() { while rx.try_recv().is_ok() {} }
+		}
 
 		// Ask and collect input, looping until a valid response is typed.
 		let mut result = String::new();
@@ -1122,15 +3021,40 @@ impl Msg {
 			if stderr { q.eprint(); }
 			else { q.print(); }
 
-			if let Some(res) = io::stdin().read_line(&mut result)
-				.ok()
-				.and_then(|_| match result.to_lowercase().trim() {
+			let read = match (rx.as_ref(), timeout) {
+				(Some(rx), Some(timeout)) => match rx.recv_timeout(timeout) {
+					Ok(line) => {
+						result = line;
+						true
+					},
+					// Time's up; move the cursor off the prompt line and go
+					// with the default.
+					Err(RecvTimeoutError::Timeout) => {
+						let _res =
+							if stderr { io::Write::write_all(&mut io::stderr(), b"\n") }
+							else { io::Write::write_all(&mut io::stdout(), b"\n") };
+						break default;
+					},
+					// The reader is gone, which is the same as an empty line.
+					Err(RecvTimeoutError::Disconnected) => true,
+				},
+				(Some(rx), None) => {
+					// As above, a missing reader is the same as an empty line.
+					if let Ok(line) = rx.recv() { result = line; }
+					true
+				},
+				(None, _) => io::stdin().read_line(&mut result).is_ok(),
+			};
+
+			if read {
+				if let Some(res) = match result.to_lowercase().trim() {
 					"" => Some(default),
 					"n" | "no" => Some(false),
 					"y" | "yes" => Some(true),
 					_ => None,
-				})
-			{ break res; }
+				}
+				{ break res; }
+			}
 
 			// Print an error and do it all over again.
 			result.truncate(0);
@@ -1143,6 +3067,144 @@ impl Msg {
 
 
 
+/// # Shared STDIN Reader.
+///
+/// Lines read from STDIN by the background thread started for timed prompts.
+/// See `stdin_lines`.
+static STDIN_LINES: OnceLock<Mutex<mpsc::Receiver<String>>> = OnceLock::new();
+
+/// # Shared STDIN Reader.
+///
+/// Return the receiving end of the shared STDIN reader, spawning it first if
+/// need be.
+///
+/// A blocking read can't be interrupted, so rather than leaving a reader
+/// behind with each timed prompt — each stealing a line from the next —
+/// there is only ever the one, and it lives as long as the program.
+fn stdin_lines() -> &'static Mutex<mpsc::Receiver<String>> {
+	STDIN_LINES.get_or_init(|| {
+		let (tx, rx) = mpsc::channel::<String>();
+		std::thread::spawn(move || {
+			let stdin = io::stdin();
+			let mut line = String::new();
+			while stdin.read_line(&mut line).is_ok_and(|len| len != 0) {
+				if tx.send(std::mem::take(&mut line)).is_err() { break; }
+			}
+		});
+		Mutex::new(rx)
+	})
+}
+
+/// # Content Hasher.
+///
+/// A small, fixed-seed folded-multiply hasher (à la `ahash`'s fallback) used
+/// by [`Msg::content_hash`]. Unlike `DefaultHasher`, its output is fully
+/// deterministic, and it chews through input eight bytes at a time.
+///
+/// Input is treated as a single stream, so splitting it across multiple
+/// writes doesn't change the result.
+struct ContentHasher {
+	/// # Running State.
+	buffer: u64,
+
+	/// # Finalization Key.
+	pad: u64,
+
+	/// # Total Length.
+	len: u64,
+
+	/// # Pending Bytes.
+	tail: [u8; 8],
+}
+
+impl Default for ContentHasher {
+	#[inline]
+	fn default() -> Self {
+		Self {
+			buffer: 0x243f_6a88_85a3_08d3,
+			pad: 0x1319_8a2e_0370_7344,
+			len: 0,
+			tail: [0; 8],
+		}
+	}
+}
+
+impl hash::Hasher for ContentHasher {
+	fn finish(&self) -> u64 {
+		let mut buffer = self.buffer;
+
+		// Mix in whatever's left over, along with the total length.
+		let rem = (self.len & 7) as usize;
+		if rem != 0 {
+			let mut word = [0_u8; 8];
+			word[..rem].copy_from_slice(&self.tail[..rem]);
+			buffer = Self::fold(u64::from_le_bytes(word) ^ buffer, Self::MULTIPLE);
+		}
+		buffer = Self::fold(self.len ^ buffer, Self::MULTIPLE);
+
+		let rot = (buffer & 63) as u32;
+		Self::fold(buffer, self.pad).rotate_left(rot)
+	}
+
+	fn write(&mut self, mut bytes: &[u8]) {
+		// Top off the pending word first, if any.
+		let rem = (self.len & 7) as usize;
+		self.len += bytes.len() as u64;
+		if rem != 0 {
+			let take = bytes.len().min(8 - rem);
+			self.tail[rem..rem + take].copy_from_slice(&bytes[..take]);
+			bytes = &bytes[take..];
+			if rem + take != 8 { return; }
+			self.update(u64::from_le_bytes(self.tail));
+		}
+
+		let mut chunks = bytes.chunks_exact(8);
+		for chunk in chunks.by_ref() {
+			self.tail.copy_from_slice(chunk);
+			self.update(u64::from_le_bytes(self.tail));
+		}
+
+		// Hold onto the rest for later.
+		let chunk = chunks.remainder();
+		self.tail[..chunk.len()].copy_from_slice(chunk);
+	}
+}
+
+impl ContentHasher {
+	/// # Multiplier.
+	const MULTIPLE: u64 = 6_364_136_223_846_793_005;
+
+	#[inline]
+	/// # Update.
+	///
+	/// Mix a word into the running state.
+	const fn update(&mut self, word: u64) {
+		self.buffer = Self::fold(word ^ self.buffer, Self::MULTIPLE);
+	}
+
+	#[inline]
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	/// # Folded Multiply.
+	///
+	/// Multiply the pair at full width, then fold the halves back together.
+	const fn fold(a: u64, b: u64) -> u64 {
+		let full = (a as u128).wrapping_mul(b as u128);
+		(full as u64) ^ ((full >> 64) as u64)
+	}
+}
+
+/// # Sanitize.
+///
+/// Strip ANSI sequences and control characters (other than tabs) from
+/// untrusted input.
+fn sanitize(src: &str) -> String {
+	NoAnsi::<char, _>::new(src.chars())
+		.filter(|&c| c == '\t' || ! c.is_control())
+		.collect()
+}
+
+
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -1174,6 +3236,23 @@ mod tests {
 		assert!(msg.ends_with(b"My dear aunt"));
 	}
 
+	#[test]
+	fn t_part_getters() {
+		let mut msg = Msg::error("My dear aunt")
+			.with_indent(1)
+			.with_suffix(" Heyo");
+		assert_eq!(msg.prefix(), "\x1b[91;1mError:\x1b[0m ");
+		assert_eq!(msg.message(), "My dear aunt");
+		assert_eq!(msg.suffix(), " Heyo");
+
+		msg.set_prefix(MsgKind::None);
+		assert_eq!(msg.prefix(), "");
+		assert_eq!(msg.message(), "My dear aunt");
+
+		msg.set_suffix("");
+		assert_eq!(msg.suffix(), "");
+	}
+
 	#[test]
 	fn t_strip_ansi() {
 		let mut msg = Msg::info("Hello \x1b[1mWorld!\x1b[0m")
@@ -1195,6 +3274,621 @@ mod tests {
 		);
 	}
 
+	#[cfg(feature = "fitted")]
+	#[test]
+	fn t_with_right_status() {
+		let msg = Msg::plain("Task name");
+		assert_eq!(msg.with_right_status("[OK]", 20), "Task name ..... [OK]");
+		assert_eq!(
+			msg.with_right_status_and_leader("[OK]", 20, ' '),
+			"Task name       [OK]",
+		);
+
+		// Tight squeeze.
+		assert_eq!(msg.with_right_status("[OK]", 15), "Task name  [OK]");
+
+		// Truncation.
+		assert_eq!(msg.with_right_status("[OK]", 10), "Task  [OK]");
+
+		// Status only.
+		assert_eq!(msg.with_right_status("[OK]", 4), "[OK]");
+		assert_eq!(msg.with_right_status("[OK]", 2), "[OK]");
+
+		// Line breaks are kept at the end.
+		let msg = msg.with_newline(true);
+		assert_eq!(msg.with_right_status("[OK]", 20), "Task name ..... [OK]\n");
+	}
+
+	#[test]
+	fn t_write_status() {
+		let msg = Msg::plain("Hello World").with_newline(true);
+		let mut out = Vec::new();
+
+		#[cfg(all(feature = "fitted", feature = "terminal_size"))]
+		{
+			assert!(msg.write_status__(&mut out, None).is_ok());
+			assert_eq!(out, b"\r\x1b[KHello World");
+
+			out.truncate(0);
+			assert!(msg.write_status__(&mut out, Some(6)).is_ok());
+			assert_eq!(out, b"\r\x1b[KHello");
+		}
+
+		#[cfg(not(all(feature = "fitted", feature = "terminal_size")))]
+		{
+			assert!(msg.write_status__(&mut out).is_ok());
+			assert_eq!(out, b"\r\x1b[KHello World");
+		}
+	}
+
+	#[test]
+	fn t_write_all() {
+		let msgs = [
+			Msg::warning("Line 5 is too long.").with_newline(true),
+			Msg::default(),
+			Msg::error("Validation failed.").with_newline(true),
+		];
+
+		let mut out = Vec::new();
+		assert!(Msg::write_all__(&mut out, &msgs).is_ok());
+
+		let expected: Vec<u8> = msgs.iter().flat_map(|m| m.iter().copied()).collect();
+		assert_eq!(out, expected);
+
+		// Nothing is nothing.
+		out.truncate(0);
+		assert!(Msg::write_all__(&mut out, &[]).is_ok());
+		assert!(out.is_empty());
+	}
+
+	#[test]
+	fn t_prompt_or_default() {
+		// This can only be tested when STDIN is not a terminal; otherwise it
+		// would actually prompt!
+		if ! io::stdin().is_terminal() {
+			let msg = Msg::new(MsgKind::Confirm, "Do you like chickens?");
+			assert!(msg.prompt_or_default(true));
+			assert!(! msg.prompt_or_default(false));
+		}
+	}
+
+	#[test]
+	fn t_as_list_item() {
+		let msg = Msg::error("Oh no!").with_indent(3);
+		assert_eq!(
+			msg.as_list_item("-", 1),
+			"    \x1b[2m-\x1b[0m \x1b[91;1mError:\x1b[0m Oh no!\n",
+		);
+		assert_eq!(
+			msg.as_list_item("", 0),
+			"\x1b[91;1mError:\x1b[0m Oh no!\n",
+		);
+
+		// The original should be unchanged.
+		assert!(msg.starts_with(b"            "));
+	}
+
+	#[cfg(feature = "fitted")]
+	#[test]
+	fn t_line_count() {
+		assert_eq!(Msg::default().line_count(80), 0);
+		assert_eq!(Msg::plain("").with_newline(true).line_count(80), 0);
+
+		let msg = Msg::plain("\x1b[1mHello\x1b[0m World");
+		assert_eq!(msg.line_count(0), 1);
+		assert_eq!(msg.line_count(11), 1);
+		assert_eq!(msg.line_count(10), 2);
+		assert_eq!(msg.line_count(5), 3);
+
+		// Blank lines count.
+		let msg = Msg::plain("Hello\n\nWorld\n").with_newline(true);
+		assert_eq!(msg.line_count(0), 4);
+		assert_eq!(msg.line_count(80), 4);
+
+		// Wide characters can't be split.
+		let msg = Msg::plain("a我b");
+		assert_eq!(msg.line_count(2), 3);
+		assert_eq!(msg.line_count(3), 2);
+		assert_eq!(msg.line_count(4), 1);
+
+		// Even when they're wider than the screen.
+		assert_eq!(msg.line_count(1), 3);
+		let msg = Msg::plain("你好世界");
+		assert_eq!(msg.line_count(1), 4);
+		assert_eq!(msg.line_count(2), 4);
+		assert_eq!(msg.line_count(3), 4);
+		assert_eq!(msg.line_count(4), 2);
+		let msg = Msg::plain("\x1b[1m你好\x1b[0m\n世界").with_newline(true);
+		assert_eq!(msg.line_count(1), 4);
+	}
+
+	#[test]
+	fn t_badge_prefix() {
+		let mut msg = Msg::error("Oh no!").with_badge_prefix("ERROR", 9);
+		assert_eq!(msg.prefix(), "\x1b[1;97;48;5;9m ERROR \x1b[0m ");
+		assert_eq!(msg.message(), "Oh no!");
+		assert!(msg.ends_with(b"\n"));
+
+		// Empty removes it.
+		msg.set_badge_prefix("", 9);
+		assert_eq!(msg.prefix(), "");
+		assert_eq!(msg.as_str(), "Oh no!\n");
+	}
+
+	#[test]
+	fn t_link() {
+		let msg = Msg::info("Docs").with_link("https://example.com/");
+		assert_eq!(msg.prefix(), Msg::info("Docs").prefix());
+		assert_eq!(
+			msg.message(),
+			"\x1b]8;;https://example.com/\x1b\\Docs\x1b]8;;\x1b\\",
+		);
+
+		// Visible text is unchanged.
+		assert_eq!(msg.without_ansi().as_str(), "Info: Docs\n");
+
+		// Bad URLs are ignored.
+		assert_eq!(Msg::info("Docs").with_link("").as_str(), Msg::info("Docs").as_str());
+		assert_eq!(
+			Msg::info("Docs").with_link("https://\x1b[0m").as_str(),
+			Msg::info("Docs").as_str(),
+		);
+
+		// As are empty messages.
+		assert_eq!(Msg::plain("").with_link("https://example.com/").as_str(), "");
+	}
+
+	#[test]
+	fn t_note() {
+		let mut msg = Msg::plain("Hello").with_note("World");
+		assert_eq!(msg.as_str(), "Hello \x1b[2m(World)\x1b[0m");
+
+		// Notes and suffixes share a slot.
+		msg.set_suffix("!");
+		assert_eq!(msg.as_str(), "Hello!");
+		msg.set_note("World");
+		assert_eq!(msg.as_str(), "Hello \x1b[2m(World)\x1b[0m");
+
+		// Empty clears.
+		msg.set_note("");
+		assert_eq!(msg.as_str(), "Hello");
+	}
+
+	#[cfg(feature = "fitted")]
+	#[test]
+	fn t_rule() {
+		assert_eq!(Msg::rule__('-', "", 5).as_str(), "\x1b[2m-----\x1b[0m\n");
+		assert_eq!(Msg::rule__('＝', "", 5).as_str(), "\x1b[2m＝＝\x1b[0m\n");
+
+		let msg = Msg::rule__('─', "Hello", 20);
+		assert_eq!(
+			msg.as_str(),
+			"\x1b[2m││││││\x1b[0;1m Hello \x1b[0;2m│││││││\x1b[0m\n".replace('│', "─"),
+		);
+		assert_eq!(crate::width(msg.as_bytes()), 20);
+
+		// Not enough room.
+		assert_eq!(Msg::rule__('─', "Hello", 8).as_str(), "\x1b[1mHello\x1b[0m\n");
+		assert_eq!(Msg::rule__('─', "Hello", 3).as_str(), "\x1b[1mHel\x1b[0m\n");
+	}
+
+	#[cfg(feature = "fitted")]
+	#[test]
+	fn t_spinner_frame() {
+		let msg = Msg::plain("Working…").with_newline(true);
+		assert_eq!(
+			msg.spinner_frame__(0, 80),
+			"\r\x1b[K\x1b[1;38;5;199m⠋\x1b[0m Working…",
+		);
+		assert_eq!(msg.spinner_frame__(11, 80), msg.spinner_frame__(1, 80));
+		assert_ne!(msg.spinner_frame__(0, 80), msg.spinner_frame__(1, 80));
+
+		// Narrow.
+		assert_eq!(
+			msg.spinner_frame__(3, 7),
+			"\r\x1b[K\x1b[1;38;5;199m⠸\x1b[0m Work",
+		);
+		assert_eq!(msg.spinner_frame__(3, 2), "\r\x1b[K\x1b[1;38;5;199m⠸\x1b[0m ");
+	}
+
+	#[test]
+	fn t_block_indent() {
+		let msg = Msg::info("One\nTwo\n\nThree").with_block_indent(2);
+		assert_eq!(msg.message(), "One\n        Two\n\n        Three");
+		assert_eq!(msg.without_ansi().as_str(), "        Info: One\n        Two\n\n        Three\n");
+
+		// Single lines are the same as regular indentation.
+		assert_eq!(
+			Msg::info("One").with_block_indent(1),
+			Msg::info("One").with_indent(1),
+		);
+
+		// Re-indenting should replace the old indentation, not add to it.
+		let mut msg = Msg::info("One\nTwo\n\nThree")
+			.with_block_indent(2)
+			.with_block_indent(1);
+		assert_eq!(msg.message(), "One\n    Two\n\n    Three");
+		assert_eq!(msg.clone().without_ansi().as_str(), "    Info: One\n    Two\n\n    Three\n");
+		msg.set_block_indent(1);
+		assert_eq!(msg.message(), "One\n    Two\n\n    Three");
+		msg.set_block_indent(0);
+		assert_eq!(msg.message(), "One\nTwo\n\nThree");
+		assert_eq!(msg, Msg::info("One\nTwo\n\nThree"));
+
+		// Lines that were already indented should keep their extra space.
+		let msg = Msg::plain("One\n  Two")
+			.with_block_indent(3)
+			.with_block_indent(1);
+		assert_eq!(msg.message(), "One\n      Two");
+
+		// A new message starts from scratch.
+		let mut msg = Msg::plain("One\nTwo").with_block_indent(1);
+		msg.set_msg("Three\n    Four");
+		msg.set_block_indent(2);
+		assert_eq!(msg.message(), "Three\n            Four");
+	}
+
+	#[test]
+	fn t_meets_severity() {
+		let min = MsgKind::Warning;
+		assert!(Msg::error("Hi").meets_severity(min));
+		assert!(Msg::warning("Hi").meets_severity(min));
+		assert!(! Msg::notice("Hi").meets_severity(min));
+		assert!(! Msg::debug("Hi").meets_severity(min));
+		assert!(! Msg::success("Hi").meets_severity(min));
+
+		// Unrecognized kinds always pass.
+		assert!(Msg::plain("Hi").meets_severity(MsgKind::Error));
+		assert!(Msg::custom("Error", 199, "Hi").meets_severity(MsgKind::Error));
+		assert!(Msg::debug("Hi").without_ansi().meets_severity(MsgKind::Error));
+
+		// Everything passes a None minimum.
+		assert!(Msg::debug("Hi").meets_severity(MsgKind::None));
+	}
+
+	#[cfg(feature = "fitted")]
+	#[test]
+	fn t_normalized() {
+		let decomposed = Msg::plain("Cafe\u{301} au lait");
+		let composed = Msg::plain("Caf\u{e9} au lait");
+		assert_ne!(decomposed, composed);
+
+		let normalized = decomposed.normalized();
+		assert_eq!(normalized, composed);
+		for width in 0..20 {
+			assert_eq!(
+				normalized.fitted(width),
+				composed.fitted(width),
+				"Fitting mismatch at {width}.",
+			);
+		}
+
+		// Nothing to do for already-composed or ASCII text.
+		let mut msg = composed.clone();
+		assert!(! msg.normalize());
+		assert_eq!(msg, composed);
+		assert!(! Msg::plain("Hello").normalize());
+	}
+
+	#[test]
+	fn t_new_sanitized() {
+		for (raw, expected) in [
+			("Hello World", "Hello World"),
+			("Hello\tWorld", "Hello\tWorld"),
+			("\x1b[1mHello\x1b[0m\nWorld", "HelloWorld"),
+			("\x1b]0;Pwned\x07Hello\x1b[3A\r\x00", "Hello"),
+			("Björk\u{9b}", "Björk"),
+		] {
+			let msg = Msg::new_sanitized(MsgKind::Warning, raw);
+			assert_eq!(msg.prefix().as_bytes(), MsgKind::Warning.as_bytes());
+			assert_eq!(msg.message(), expected);
+
+			let msg = Msg::new_sanitized(("Hello", 4), raw);
+			assert_eq!(msg, Msg::custom("Hello", 4, expected));
+
+			let msg = Msg::plain("Hello").with_sanitized_suffix(raw);
+			assert_eq!(msg.message(), "Hello");
+			assert_eq!(msg.suffix(), expected);
+		}
+	}
+
+	#[test]
+	fn t_section_markers() {
+		let msg = Msg::info("Hello").with_suffix("!").with_newline(true);
+		let marked = msg.clone().with_section_markers();
+		assert_eq!(marked.message(), "\x1b]133;A\x07Hello\x1b]133;C\x07");
+		assert_eq!(marked.prefix(), msg.prefix());
+		assert_eq!(marked.suffix(), msg.suffix());
+		assert_eq!(marked.clone().with_section_markers(), marked);
+
+		// Empty is fine too.
+		assert_eq!(
+			Msg::plain("").with_section_markers().as_bytes(),
+			b"\x1b]133;A\x07\x1b]133;C\x07",
+		);
+	}
+
+	#[test]
+	fn t_limit_lines() {
+		for (raw, max, expected) in [
+			("One", 1, "One"),
+			("One\nTwo\n", 2, "One\nTwo\n"),
+			("One\nTwo\nThree", 2, "One\nTwo\n\x1b[2m…(1 more line)\x1b[0m"),
+			("One\nTwo\nThree\n", 1, "One\n\x1b[2m…(2 more lines)\x1b[0m"),
+			("One\nTwo", 0, "\x1b[2m…(2 more lines)\x1b[0m"),
+			("One\n  Two\n  Three", 2, "One\n  Two\n  \x1b[2m…(1 more line)\x1b[0m"),
+			("", 0, ""),
+		] {
+			let mut msg = Msg::warning(raw).with_suffix("!");
+			msg.limit_lines(max);
+			assert_eq!(msg.message(), expected, "Limiting {raw:?} to {max}.");
+			assert_eq!(msg.suffix(), "!");
+		}
+
+		// Thousands get commas.
+		let mut msg = Msg::plain("x\n".repeat(1502));
+		msg.limit_lines(1);
+		assert_eq!(msg.message(), "x\n\x1b[2m…(1,501 more lines)\x1b[0m");
+	}
+
+	#[cfg(feature = "fitted")]
+	#[test]
+	fn t_limit_lines_fitted() {
+		for (raw, max, width, expected) in [
+			// Plenty of room.
+			("One\n  Two\n  Three", 2, 80, "One\n  Two\n  \x1b[2m…(1 more line)\x1b[0m"),
+			// The lead goes first.
+			("One\n  Two\n  Three", 2, 14, "One\n  Two\n\x1b[2m…(1 more line)\x1b[0m"),
+			// Then the note.
+			("One\n  Two\n  Three", 2, 8, "One\n  Two\n\x1b[2m…(1 more\x1b[0m"),
+			("One\nTwo", 1, 0, "One\n"),
+			// The prefix counts when nothing is kept.
+			("One\nTwo", 0, 17, "\x1b[2m…(2 more l\x1b[0m"),
+		] {
+			let mut msg =
+				if max == 0 { Msg::custom("Hello", 199, raw) }
+				else { Msg::plain(raw) };
+			msg.limit_lines__(max, width);
+			assert_eq!(msg.message(), expected, "Limiting {raw:?} to {max}/{width}.");
+		}
+	}
+
+	#[test]
+	fn t_quoted_arg() {
+		let msg = Msg::plain("Could not find ")
+			.with_quoted_arg("foo.txt")
+			.with_quoted_arg("\x1b]8;;https://x.com\x1b\\bar\r\x07\u{9b}31m\tbaz\x1b]8;;\x1b\\");
+		assert_eq!(
+			msg.message(),
+			"Could not find '\x1b[1;4mfoo.txt\x1b[0m''\x1b[1;4mbar31m\tbaz\x1b[0m'",
+		);
+
+		// An empty argument still gets quoted.
+		assert_eq!(Msg::plain("").with_quoted_arg("").message(), "'\x1b[1;4m\x1b[0m'");
+	}
+
+	#[test]
+	fn t_content_hasher() {
+		use std::hash::Hasher;
+
+		// Splitting the input shouldn't affect the result.
+		let src = b"The quick brown fox jumps over the lazy dog.";
+		let mut h = ContentHasher::default();
+		h.write(src);
+		let expected = h.finish();
+		for a in 0..src.len() {
+			for b in a..src.len() {
+				let mut h = ContentHasher::default();
+				h.write(&src[..a]);
+				h.write(&src[a..b]);
+				h.write(&src[b..]);
+				assert_eq!(h.finish(), expected, "Split at {a}/{b}.");
+			}
+		}
+
+		// Trailing zeroes still count.
+		let mut h = ContentHasher::default();
+		h.write(b"ab");
+		let mut h2 = ContentHasher::default();
+		h2.write(b"ab\0");
+		assert_ne!(h.finish(), h2.finish());
+	}
+
+	#[test]
+	fn t_content_hash() {
+		let hash = Msg::error("Oh no!").content_hash();
+
+		// The seeds are fixed, so the value shouldn't ever drift.
+		assert_eq!(hash, 0x49af_790d_7067_d078);
+		assert_eq!(Msg::error("Oh no!").content_hash(), hash);
+		assert_eq!(Msg::error("Oh no!").with_indent(2).with_newline(false).content_hash(), hash);
+		assert_eq!(Msg::error("Oh no!").without_ansi().content_hash(), hash);
+		assert_eq!(Msg::custom("Error", 4, "Oh no!").with_newline(true).content_hash(), hash);
+
+		// The content still matters.
+		assert_ne!(Msg::error("Oh no!!").content_hash(), hash);
+		assert_ne!(Msg::warning("Oh no!").content_hash(), hash);
+		assert_ne!(Msg::error("Oh no!").with_suffix("!").content_hash(), hash);
+
+		// As do the boundaries.
+		assert_ne!(
+			Msg::plain("AB").content_hash(),
+			Msg::plain("A").with_suffix("B").content_hash(),
+		);
+
+		// Formatting in the middle of the message shouldn't matter either.
+		assert_eq!(
+			Msg::plain("\x1b[1mHello\x1b[0m \x1b[2mWorld\x1b[0m!").content_hash(),
+			Msg::plain("Hello World!").content_hash(),
+		);
+	}
+
+	#[test]
+	fn t_kv() {
+		let msg = Msg::kv("Key", "Value");
+		assert_eq!(msg.as_str(), "\x1b[2mKey:\x1b[0m Value");
+		assert_eq!(msg.prefix(), "\x1b[2mKey:\x1b[0m ");
+		assert_eq!(msg.message(), "Value");
+	}
+
+	#[cfg(feature = "fitted")]
+	#[test]
+	fn t_kv_aligned() {
+		let msgs = Msg::kv_aligned(&[("Björk", "1"), ("A", "2"), ("Name", "3")]);
+		assert_eq!(msgs.len(), 3);
+		assert_eq!(msgs[0].as_str(), "\x1b[2mBjörk:\x1b[0m 1\n");
+		assert_eq!(msgs[1].as_str(), "\x1b[2mA:\x1b[0m     2\n");
+		assert_eq!(msgs[2].as_str(), "\x1b[2mName:\x1b[0m  3\n");
+
+		assert!(Msg::kv_aligned(&[]).is_empty());
+	}
+
+	#[test]
+	fn t_macros() {
+		let path = "/foo/bar.jpg";
+		assert_eq!(msg_error!("Unable to open {path}."), Msg::error("Unable to open /foo/bar.jpg."));
+		assert_eq!(msg_info!("{} + {} = {}", 1, 2, 3), Msg::info("1 + 2 = 3"));
+		assert_eq!(msg_warning!("Careful!"), Msg::warning("Careful!"));
+	}
+
+	#[test]
+	fn t_new_owned() {
+		for kind in [MsgKind::None, MsgKind::Confirm, MsgKind::Error, MsgKind::Task] {
+			for text in ["", "Hello World", "Björk Guðmundsdóttir"] {
+				assert_eq!(
+					Msg::new_owned(kind, text.to_owned()),
+					Msg::new(kind, text),
+					"New owned mismatch for {kind:?} {text:?}.",
+				);
+			}
+		}
+
+		// Custom prefixes too.
+		for prefix in [("", 4), ("Hello", 4), ("Björk", 199)] {
+			assert_eq!(
+				Msg::new_owned(prefix, "World".to_owned()),
+				Msg::custom(prefix.0, prefix.1, "World"),
+				"New owned mismatch for {prefix:?}.",
+			);
+		}
+
+		// Make sure the parts are where they should be.
+		let mut msg = Msg::new_owned(MsgKind::Error, "Hello World".to_owned());
+		msg.set_msg("Goodbye");
+		assert_eq!(msg, Msg::new(MsgKind::Error, "Goodbye"));
+
+		let mut msg = Msg::new_owned(("Hello", 4), "World".to_owned());
+		msg.set_msg("Goodbye");
+		assert_eq!(msg, Msg::custom("Hello", 4, "Goodbye"));
+	}
+
+	#[test]
+	fn t_from_cow() {
+		// Borrowed.
+		let msg = Msg::from(Cow::Borrowed("Hello World"));
+		assert_eq!(msg, Msg::plain("Hello World"));
+
+		// Owned; the allocation should be reused.
+		let owned = String::from("Hello World");
+		let ptr = owned.as_ptr();
+		let msg = Msg::from(Cow::<str>::Owned(owned));
+		assert_eq!(msg, Msg::plain("Hello World"));
+		assert_eq!(msg.as_ptr(), ptr);
+	}
+
+	#[test]
+	fn t_constants() {
+		// The built-in prefixes should all use the same reset/separator.
+		for kind in [MsgKind::Error, MsgKind::Info, MsgKind::Success] {
+			let prefix = std::str::from_utf8(kind.as_bytes()).expect("Invalid UTF-8.");
+			assert!(
+				prefix.ends_with(Msg::SEPARATOR_ANSI),
+				"Unexpected prefix format: {prefix:?}",
+			);
+		}
+
+		// Custom prefixes too.
+		let msg = Msg::custom("Prefix", 199, "Hello World");
+		assert!(msg.as_str().contains(&format!("Prefix{}", Msg::SEPARATOR_ANSI)));
+
+		// The formatted separator is just the plain one with a reset.
+		assert_eq!(
+			Msg::SEPARATOR_ANSI,
+			format!("{}{}{}", &Msg::SEPARATOR[..1], Msg::RESET, &Msg::SEPARATOR[1..]),
+		);
+
+		// And the plain one shows up once the formatting is gone.
+		assert_eq!(msg.without_ansi().as_str(), format!("Prefix{}Hello World", Msg::SEPARATOR));
+	}
+
+	#[cfg(feature = "fitted")]
+	#[test]
+	fn t_display() {
+		let msg = Msg::new(MsgKind::Error, "Björk");
+		assert_eq!(format!("{msg}"), msg.as_str());
+
+		// Padding is based on the display width, which is 12 here.
+		assert_eq!(format!("{msg:5}"), msg.as_str());
+		assert_eq!(format!("{msg:14}"), format!("{}  ", msg.as_str()));
+		assert_eq!(format!("{msg:<14}"), format!("{}  ", msg.as_str()));
+		assert_eq!(format!("{msg:>14}"), format!("  {}", msg.as_str()));
+		assert_eq!(format!("{msg:-^15}"), format!("-{}--", msg.as_str()));
+
+		// Padding should go before the line break.
+		let msg = msg.with_newline(true);
+		assert_eq!(
+			format!("{msg:.<14}"),
+			"\x1b[91;1mError:\x1b[0m Björk..\n",
+		);
+	}
+
+	#[cfg(all(unix, feature = "syslog"))]
+	#[test]
+	fn t_syslog_body() {
+		let msg = Msg::error("Oh \x1b[1mno\x1b[0m!")
+			.with_indent(1)
+			.with_suffix(" (x)")
+			.with_newline(true);
+		assert_eq!(msg.syslog_body(), b"Oh no! (x)");
+
+		let msg = Msg::custom("Hmm", 199, "Well then.");
+		assert_eq!(msg.syslog_body(), b"Hmm: Well then.");
+	}
+
+	#[cfg(feature = "termcolor")]
+	#[test]
+	fn t_write_color() {
+		/// # Synchronous Writer.
+		struct Sync(Vec<u8>);
+		impl io::Write for Sync {
+			fn write(&mut self, buf: &[u8]) -> io::Result<usize> { self.0.write(buf) }
+			fn flush(&mut self) -> io::Result<()> { Ok(()) }
+		}
+		impl termcolor::WriteColor for Sync {
+			fn supports_color(&self) -> bool { true }
+			fn set_color(&mut self, _spec: &termcolor::ColorSpec) -> io::Result<()> { Ok(()) }
+			fn reset(&mut self) -> io::Result<()> { Ok(()) }
+			fn is_synchronous(&self) -> bool { true }
+		}
+
+		let msg = Msg::info("Hello \x1b[1mWorld!\x1b[0m");
+
+		// Color-capable writers get the original bytes.
+		let mut buf = termcolor::Buffer::ansi();
+		assert!(msg.write_color(&mut buf).is_ok());
+		assert_eq!(buf.as_slice(), msg.as_bytes());
+
+		// Colorless writers get it stripped.
+		let mut buf = termcolor::Buffer::no_color();
+		assert!(msg.write_color(&mut buf).is_ok());
+		assert_eq!(buf.as_slice(), b"Info: Hello World!\n");
+
+		// As do synchronous ones, even if they technically support color.
+		let mut buf = Sync(Vec::new());
+		assert!(msg.write_color(&mut buf).is_ok());
+		assert_eq!(buf.0, b"Info: Hello World!\n");
+	}
+
 	#[cfg(feature = "fitted")]
 	#[test]
 	fn t_fitted() {
@@ -1220,5 +3914,17 @@ mod tests {
 		// Try it with Unicode!
 		msg.set_msg("Björk Guðmundsdóttir");
 		assert_eq!(msg.fitted(12), "\x1b[91;1mError:\x1b[0m Björk\n".as_bytes());
+
+		// Tabs have no width by default…
+		msg.set_msg("a\tb");
+		assert_eq!(msg.fitted(9), &b"\x1b[91;1mError:\x1b[0m a\tb\n"[..]);
+
+		// …but can be expanded relative to the start of the line.
+		let tab = crate::TAB_STOP;
+		assert_eq!(msg.fitted_with_tab_stop(17, tab), &b"\x1b[91;1mError:\x1b[0m a\tb\n"[..]);
+		assert_eq!(msg.fitted_with_tab_stop(16, tab), &b"\x1b[91;1mError:\x1b[0m a\t\n"[..]);
+		assert_eq!(msg.fitted_with_tab_stop(15, tab), &b"\x1b[91;1mError:\x1b[0m a\n"[..]);
+		assert_eq!(msg.fitted_with_tab_stop(10, 4), &b"\x1b[91;1mError:\x1b[0m a\n"[..]);
+		assert_eq!(msg.fitted_with_tab_stop(10, 0), &b"\x1b[91;1mError:\x1b[0m a\tb\n"[..]);
 	}
 }
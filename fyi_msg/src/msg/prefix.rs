@@ -0,0 +1,161 @@
+/*!
+# FYI Msg: Prefixes
+*/
+
+use crate::MsgKind;
+use dactyl::NiceU8;
+
+
+
+/// # Into Message Prefix.
+///
+/// This trait covers the types that can be used as a message prefix with
+/// [`Msg::new_owned`](crate::Msg::new_owned) and
+/// [`Msg::new_sanitized`](crate::Msg::new_sanitized).
+///
+/// It is implemented for [`MsgKind`] — the built-ins — as well as for
+/// `(label, color)` tuples, which are formatted the same way as
+/// [`Msg::custom`](crate::Msg::custom), and [`MsgBadge`]s. (An empty label
+/// means no prefix.)
+///
+/// ## Examples
+///
+/// ```
+/// use fyi_msg::{Msg, MsgBadge, MsgKind};
+///
+/// // Built-in.
+/// assert_eq!(
+///     Msg::new_owned(MsgKind::Error, "Oh no!".to_owned()),
+///     Msg::error("Oh no!").with_newline(false),
+/// );
+///
+/// // Custom.
+/// assert_eq!(
+///     Msg::new_owned(("Lint", 199), "Line 5 is too long.".to_owned()),
+///     Msg::custom("Lint", 199, "Line 5 is too long."),
+/// );
+///
+/// // Badge.
+/// assert_eq!(
+///     Msg::new_owned(MsgBadge("LINT", 199), "Line 5 is too long.".to_owned()),
+///     Msg::plain("Line 5 is too long.").with_badge_prefix("LINT", 199),
+/// );
+/// ```
+pub trait IntoMsgPrefix {
+	/// # Prefix Length.
+	///
+	/// Return the byte length of the formatted prefix, including any ANSI
+	/// sequences and the trailing separator.
+	fn prefix_len(&self) -> usize;
+
+	/// # Push Prefix.
+	///
+	/// Append the formatted prefix to `dst`.
+	fn prefix_push(&self, dst: &mut Vec<u8>);
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Badge Prefix.
+///
+/// A `(label, color)` prefix formatted as a badge — bold white text on a
+/// colored background — the same way as
+/// [`Msg::with_badge_prefix`](crate::Msg::with_badge_prefix), for use
+/// anywhere an [`IntoMsgPrefix`] is accepted.
+///
+/// ## Examples
+///
+/// ```
+/// use fyi_msg::{Msg, MsgBadge, MsgTemplate};
+///
+/// let tpl = MsgTemplate::new(MsgBadge("ERROR", 9));
+/// assert_eq!(tpl.prefix(), "\x1b[1;97;48;5;9m ERROR \x1b[0m ");
+///
+/// let msg = Msg::new_sanitized(MsgBadge("ERROR", 9), "The server is on fire!");
+/// assert_eq!(msg.prefix(), tpl.prefix());
+/// ```
+pub struct MsgBadge<S>(pub S, pub u8);
+
+impl IntoMsgPrefix for MsgKind {
+	#[inline]
+	fn prefix_len(&self) -> usize { self.as_bytes().len() }
+
+	#[inline]
+	fn prefix_push(&self, dst: &mut Vec<u8>) { dst.extend_from_slice(self.as_bytes()); }
+}
+
+impl<S: AsRef<str>> IntoMsgPrefix for (S, u8) {
+	#[inline]
+	fn prefix_len(&self) -> usize {
+		let label = self.0.as_ref();
+		if label.is_empty() { 0 }
+		else { 16 + NiceU8::from(self.1).len() + label.len() }
+	}
+
+	fn prefix_push(&self, dst: &mut Vec<u8>) {
+		let label = self.0.as_ref().as_bytes();
+		if ! label.is_empty() {
+			dst.extend_from_slice(b"\x1b[1;38;5;");
+			dst.extend_from_slice(NiceU8::from(self.1).as_bytes());
+			dst.push(b'm');
+			dst.extend_from_slice(label);
+			dst.extend_from_slice(crate::Msg::SEPARATOR_ANSI.as_bytes());
+		}
+	}
+}
+
+impl<S: AsRef<str>> IntoMsgPrefix for MsgBadge<S> {
+	#[inline]
+	fn prefix_len(&self) -> usize {
+		let label = self.0.as_ref();
+		if label.is_empty() { 0 }
+		else { 20 + NiceU8::from(self.1).len() + label.len() }
+	}
+
+	fn prefix_push(&self, dst: &mut Vec<u8>) {
+		let label = self.0.as_ref().as_bytes();
+		if ! label.is_empty() {
+			dst.extend_from_slice(b"\x1b[1;97;48;5;");
+			dst.extend_from_slice(NiceU8::from(self.1).as_bytes());
+			dst.extend_from_slice(b"m ");
+			dst.extend_from_slice(label);
+			dst.extend_from_slice(b" \x1b[0m ");
+		}
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::Msg;
+
+	#[test]
+	fn t_prefix_len() {
+		for kind in [MsgKind::None, MsgKind::Confirm, MsgKind::Error, MsgKind::Task] {
+			let mut buf = Vec::new();
+			kind.prefix_push(&mut buf);
+			assert_eq!(buf, kind.as_bytes());
+			assert_eq!(kind.prefix_len(), buf.len());
+		}
+
+		for (label, color) in [("", 4), ("Hello", 4), ("Björk", 199)] {
+			let mut buf = Vec::new();
+			(label, color).prefix_push(&mut buf);
+			assert_eq!(
+				buf,
+				Msg::custom(label, color, "").as_bytes(),
+				"Prefix mismatch for {label:?}.",
+			);
+			assert_eq!((label, color).prefix_len(), buf.len());
+
+			let mut buf = Vec::new();
+			MsgBadge(label, color).prefix_push(&mut buf);
+			let expected =
+				if label.is_empty() { String::new() }
+				else { format!("\x1b[1;97;48;5;{color}m {label} \x1b[0m ") };
+			assert_eq!(buf, expected.as_bytes(), "Badge mismatch for {label:?}.");
+			assert_eq!(MsgBadge(label, color).prefix_len(), buf.len());
+		}
+	}
+}
@@ -0,0 +1,159 @@
+/*!
+# FYI Msg: Templates
+*/
+
+use crate::{
+	IntoMsgPrefix,
+	Msg,
+	MsgKind,
+};
+
+
+
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq)]
+/// # Message Template.
+///
+/// This captures a prefix — built-in or custom — once, and stamps out
+/// [`Msg`] instances differing only in body, reusing the pre-formatted prefix
+/// bytes rather than rebuilding them each time.
+///
+/// This is handy for hot reporting loops that print many lines under the
+/// same label.
+///
+/// ## Examples
+///
+/// ```
+/// use fyi_msg::{Msg, MsgKind, MsgTemplate};
+///
+/// let tpl = MsgTemplate::custom("Lint", 199).with_newline(true);
+/// for line in [5, 9] {
+///     let msg = tpl.msg(format!("Line {line} is too long."));
+///     assert_eq!(msg.prefix(), tpl.prefix());
+///     msg.print();
+/// }
+///
+/// // Built-ins work too.
+/// let tpl = MsgTemplate::from(MsgKind::Warning).with_newline(true);
+/// assert_eq!(tpl.msg("Careful!"), Msg::warning("Careful!"));
+///
+/// // As does anything else implementing IntoMsgPrefix.
+/// let tpl = MsgTemplate::new(("Lint", 199));
+/// assert_eq!(tpl, MsgTemplate::custom("Lint", 199));
+/// ```
+pub struct MsgTemplate {
+	/// # Prefix.
+	prefix: String,
+
+	/// # Trailing Line Break?
+	newline: bool,
+}
+
+impl From<MsgKind> for MsgTemplate {
+	#[inline]
+	fn from(kind: MsgKind) -> Self { Self::new(kind) }
+}
+
+/// ## Instantiation.
+impl MsgTemplate {
+	#[expect(clippy::needless_pass_by_value, reason = "Prefixes are usually cheap temporaries.")]
+	#[must_use]
+	/// # New.
+	///
+	/// Create a template from any [`IntoMsgPrefix`] type — a [`MsgKind`], a
+	/// `(label, color)` pair, etc. — formatting the prefix once, up front.
+	pub fn new<P>(prefix: P) -> Self
+	where P: IntoMsgPrefix {
+		let mut buf = Vec::with_capacity(prefix.prefix_len());
+		prefix.prefix_push(&mut buf);
+		Self {
+			prefix: String::from_utf8(buf).unwrap_or_default(),
+			newline: false,
+		}
+	}
+
+	#[must_use]
+	#[inline]
+	/// # Custom Prefix.
+	///
+	/// Create a template with a user-defined prefix and color, formatted
+	/// exactly as with [`Msg::custom`].
+	pub fn custom<S>(prefix: S, color: u8) -> Self
+	where S: AsRef<str> { Self::new((prefix, color)) }
+
+	#[must_use]
+	/// # Custom Prefix (Pre-formatted).
+	///
+	/// Create a template with a prefix used as-is, exactly as with
+	/// [`Msg::custom_preformatted`].
+	pub fn custom_preformatted<S>(prefix: S) -> Self
+	where S: Into<String> {
+		Self { prefix: prefix.into(), newline: false }
+	}
+
+	#[must_use]
+	/// # With Trailing Line Break.
+	///
+	/// Set whether or not the generated messages should end with a line break.
+	pub const fn with_newline(mut self, newline: bool) -> Self {
+		self.newline = newline;
+		self
+	}
+}
+
+/// ## Details.
+impl MsgTemplate {
+	#[must_use]
+	#[inline]
+	/// # Prefix.
+	///
+	/// Return the (formatted) prefix.
+	pub fn prefix(&self) -> &str { &self.prefix }
+
+	#[must_use]
+	/// # New Message.
+	///
+	/// Return a new [`Msg`] with the template's prefix (and line break, if
+	/// any) and the given body.
+	pub fn msg<S>(&self, msg: S) -> Msg
+	where S: AsRef<str> {
+		Msg::custom_preformatted(self.prefix.as_str(), msg.as_ref())
+			.with_newline(self.newline)
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_msg() {
+		// Built-ins.
+		for kind in [MsgKind::None, MsgKind::Error, MsgKind::Success, MsgKind::Task] {
+			let tpl = MsgTemplate::from(kind);
+			assert_eq!(tpl.msg("Hello World"), Msg::new(kind, "Hello World"));
+
+			let tpl = tpl.with_newline(true);
+			assert_eq!(
+				tpl.msg("Hello World"),
+				Msg::new(kind, "Hello World").with_newline(true),
+			);
+		}
+
+		// Customs.
+		let tpl = MsgTemplate::custom("Hello", 4);
+		assert_eq!(tpl.msg("World"), Msg::custom("Hello", 4, "World"));
+		assert_eq!(MsgTemplate::custom("", 4).msg("World"), Msg::plain("World"));
+
+		// Generics.
+		assert_eq!(MsgTemplate::new(MsgKind::Error), MsgTemplate::from(MsgKind::Error));
+		assert_eq!(MsgTemplate::new(("Hello", 4)), tpl);
+		assert_eq!(MsgTemplate::new((String::from("Hello"), 4)), tpl);
+		assert_eq!(MsgTemplate::new(("", 4)).prefix(), "");
+
+		let tpl = MsgTemplate::custom_preformatted("e.g. ");
+		assert_eq!(tpl.prefix(), "e.g. ");
+		assert_eq!(tpl.msg("World"), Msg::custom_preformatted("e.g. ", "World"));
+	}
+}
@@ -17,6 +17,8 @@ pub enum ProglessError {
 	EmptyTotal,
 	/// # Length (total) overflow.
 	TotalOverflow,
+	/// # Invalid template.
+	InvalidTemplate,
 }
 
 impl AsRef<str> for ProglessError {
@@ -40,6 +42,7 @@ impl ProglessError {
 	pub const fn as_str(self) -> &'static str {
 		match self {
 			Self::EmptyTotal => "At least one task is required.",
+			Self::InvalidTemplate => "Templates may only contain text and the placeholders {elapsed}, {bar}, {pos}, {len}, {percent}, and {msg}.",
 
 			#[cfg(target_pointer_width = "16")]
 			Self::TotalOverflow => "Progress can only be displayed for up to 65,535 items.",
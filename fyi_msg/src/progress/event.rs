@@ -0,0 +1,48 @@
+/*!
+# FYI Msg - Progless Events
+*/
+
+use std::time::Duration;
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "progress")))]
+#[derive(Debug, Clone, Eq, PartialEq)]
+/// # Progress Event.
+///
+/// These are the structured progress milestones sent to the channel set via
+/// [`Progless::with_event_sender`](crate::Progless::with_event_sender),
+/// useful for forwarding progress to web UIs, metrics systems, and the like.
+pub enum ProglessEvent {
+	/// # Started.
+	///
+	/// Progress has begun, or begun anew following a reset.
+	Started {
+		/// # Total.
+		total: u32,
+	},
+
+	/// # Progress.
+	///
+	/// The done count has changed. (This is checked once per tick, so rapid
+	/// changes will be coalesced.)
+	Progress {
+		/// # Done.
+		done: u32,
+
+		/// # Total.
+		total: u32,
+	},
+
+	/// # Task Added.
+	TaskAdded(String),
+
+	/// # Task Removed.
+	TaskRemoved(String),
+
+	/// # Finished.
+	Finished {
+		/// # Elapsed Time.
+		elapsed: Duration,
+	},
+}
@@ -4,8 +4,11 @@
 
 pub(super) mod ba;
 pub(super) mod error;
+pub(super) mod event;
+pub(super) mod snapshot;
 mod steady;
 mod task;
+mod template;
 
 #[cfg(any(feature = "signals_sigint", feature = "signals_sigwinch"))]
 pub(super) mod signals;
@@ -16,11 +19,13 @@ use crate::{
 	Msg,
 	MsgKind,
 	ProglessError,
+	ProglessEvent,
 };
 use dactyl::{
 	NiceClock,
 	NiceElapsed,
 	NicePercent,
+	NiceU8,
 	NiceU32,
 	traits::{
 		NiceInflection,
@@ -28,6 +33,7 @@ use dactyl::{
 	},
 };
 use std::{
+	borrow::Cow,
 	collections::BTreeSet,
 	io::{
 		IoSlice,
@@ -45,11 +51,14 @@ use std::{
 	sync::{
 		Arc,
 		Mutex,
+		mpsc::SyncSender,
 		atomic::{
+			AtomicBool,
 			AtomicU8,
 			AtomicU16,
 			AtomicU32,
 			AtomicU64,
+			AtomicUsize,
 			Ordering::SeqCst,
 		},
 	},
@@ -58,8 +67,16 @@ use std::{
 		Instant,
 	},
 };
+use snapshot::{
+	ProglessRenderer,
+	ProglessSnapshot,
+};
 use steady::ProglessSteady;
 use task::ProglessTask;
+use template::{
+	ProglessTemplate,
+	ProglessToken,
+};
 
 
 
@@ -69,12 +86,72 @@ static BAR_DONE:   [u8; 256] = [b'#'; 256];
 /// # Dash Filler (TBD).
 static BAR_UNDONE: [u8; 256] = [b'-'; 256];
 
+/// # Block Filler (Done, Smooth).
+///
+/// This holds 255 full blocks (`█`), each three bytes wide.
+static BAR_DONE_SMOOTH: [u8; 765] = {
+	let mut out = [0_u8; 765];
+	let mut i = 0;
+	while i < 765 {
+		out[i] = 0xE2;
+		out[i + 1] = 0x96;
+		out[i + 2] = 0x88;
+		i += 3;
+	}
+	out
+};
+
+/// # Partial Block Fillers (Done, Smooth).
+///
+/// These are the one-through-seven-eighths blocks, indexed by eighth.
+static BAR_PARTIAL: [&[u8]; 8] = [
+	b"",
+	"\u{258F}".as_bytes(),
+	"\u{258E}".as_bytes(),
+	"\u{258D}".as_bytes(),
+	"\u{258C}".as_bytes(),
+	"\u{258B}".as_bytes(),
+	"\u{258A}".as_bytes(),
+	"\u{2589}".as_bytes(),
+];
+
 /// # Clear Screen.
 ///
 /// This ANSI sequence is used to clear the screen from the current cursor
 /// position (i.e. everything _after_).
 const CLS: &[u8] = b"\x1b[J";
 
+/// # Active Painter.
+///
+/// This holds the ID of the `ProglessInner` instance currently drawing to
+/// STDERR, or zero if none.
+///
+/// Only one instance can paint at a time, otherwise they'd scramble each
+/// other's cursor math. The rest continue to track their progress, but stay
+/// quiet until the screen frees up.
+///
+/// The claim is released when the painter stops (or is dropped), so an
+/// instance that is leaked while running will keep the screen to itself.
+static PAINTER: AtomicUsize = AtomicUsize::new(0);
+
+/// # Next Painter ID.
+///
+/// Each `ProglessInner` gets its own ID from this counter. It starts at one
+/// because zero means "nobody" to `PAINTER`.
+static NEXT_ID: AtomicUsize = AtomicUsize::new(1);
+
+/// # Handed-Off Messages.
+///
+/// Messages pushed to an instance that isn't painting while some other one
+/// is. Printing them directly would scramble the active frame, so they're
+/// left here for the painter to write out with its own.
+static HANDOFF: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+
+/// # Clear Terminal Progress.
+///
+/// This OSC 9;4 sequence removes the terminal's native progress indicator.
+const OSC_PROGRESS_CLEAR: &[u8] = b"\x1b]9;4;0\x07";
+
 /// # Helper: Mutex Unlock.
 ///
 /// This just moves tedious code out of the way.
@@ -170,6 +247,11 @@ const MIN_DRAW_WIDTH: u8 = 10;
 /// struct holds an instance of this behind an [`std::sync::Arc`] for easier
 /// thread-sharing.
 struct ProglessInner {
+	/// # Unique ID.
+	///
+	/// This identifies the instance to the global `PAINTER` guard.
+	id: usize,
+
 	/// # Buffer.
 	buf: Mutex<ProglessBuffer>,
 
@@ -186,9 +268,16 @@ struct ProglessInner {
 	/// # Start Time.
 	///
 	/// The instant the object was first created. All timings are derived from
-	/// this value.
+	/// this value (by way of `ProglessInner::elapsed`).
 	started: Instant,
 
+	#[cfg(test)]
+	/// # Clock Skew (Milliseconds).
+	///
+	/// Tests can't sit around waiting for real time to pass, so this lets them
+	/// fast-forward the clock instead.
+	skew: AtomicU64,
+
 	/// # Elapsed Seconds.
 	///
 	/// The number of elapsed seconds as of the last tick (so we know when to
@@ -207,35 +296,98 @@ struct ProglessInner {
 
 	/// # Active Task List.
 	doing: Mutex<BTreeSet<ProglessTask>>,
+
+	/// # Pending Messages.
+	///
+	/// Messages pushed while the progress is on screen are collected here and
+	/// written all at once with the next tick, so a flood of them only costs
+	/// a single clear and repaint.
+	msgs: Mutex<Vec<u8>>,
+
+	/// # Persist on Finish?
+	///
+	/// When `true`, the final frame is left on screen rather than erased.
+	persist: AtomicBool,
+
+	/// # Allow Overshoot?
+	///
+	/// When `true`, reaching (or exceeding) the total caps the done count
+	/// rather than stopping the progress.
+	overshoot: AtomicBool,
+
+	/// # Custom Renderer.
+	///
+	/// When set, ticks are passed to this callback instead of being painted
+	/// to STDERR.
+	renderer: Mutex<Option<ProglessRenderer>>,
+
+	/// # Event Sender.
+	///
+	/// When set, structured progress events are sent here.
+	events: Mutex<Option<SyncSender<ProglessEvent>>>,
+
+	/// # Last Reported Done/Total.
+	///
+	/// The `done_total` value as of the last `ProglessEvent::Progress`, so we
+	/// know when to send another.
+	events_done: AtomicU64,
 }
 
 impl Default for ProglessInner {
 	#[inline]
-	fn default() -> Self {
+	fn default() -> Self { Self::new(0, 1) }
+}
+
+/// # Construction.
+impl ProglessInner {
+	/// # New.
+	///
+	/// Create a new instance with the given starting flags and done/total.
+	///
+	/// Because of the `Drop` impl, struct update syntax is off the table, so
+	/// the `From` impls all route through here instead.
+	fn new(flags: u8, done_total: u64) -> Self {
 		Self {
+			id: NEXT_ID.fetch_add(1, SeqCst),
 			buf: Mutex::new(ProglessBuffer::DEFAULT),
-			flags: AtomicU8::new(0),
+			flags: AtomicU8::new(flags),
 
 			last_size: AtomicU16::new(0),
 
 			started: Instant::now(),
+			#[cfg(test)] skew: AtomicU64::new(0),
 			elapsed: AtomicU32::new(0),
 
 			title: Mutex::new(None),
-			done_total: AtomicU64::new(1),
+			done_total: AtomicU64::new(done_total),
 			doing: Mutex::new(BTreeSet::default()),
+			msgs: Mutex::new(Vec::new()),
+			persist: AtomicBool::new(false),
+			overshoot: AtomicBool::new(false),
+			renderer: Mutex::new(None),
+			events: Mutex::new(None),
+			events_done: AtomicU64::new(0),
 		}
 	}
 }
 
+impl Drop for ProglessInner {
+	/// # Drop.
+	///
+	/// Make sure progress has been properly stopped — screen cleared, cursor
+	/// restored, etc. — even if [`Progless::finish`] was never called, e.g.
+	/// because of a panic or early return.
+	///
+	/// Note: the steady ticker holds a reference of its own, but it is always
+	/// shut down (by `ProglessSteady`'s own `Drop`) before the last
+	/// `Progless` lets go of this.
+	fn drop(&mut self) { self.stop(); }
+}
+
 impl From<NonZeroU32> for ProglessInner {
 	#[inline]
 	fn from(total: NonZeroU32) -> Self {
-		Self {
-			flags: AtomicU8::new(TICK_NEW),
-			done_total: AtomicU64::new(u64::from(total.get())),
-			..Self::default()
-		}
+		Self::new(TICK_NEW, u64::from(total.get()))
 	}
 }
 
@@ -245,11 +397,7 @@ macro_rules! inner_nz_from {
 		impl From<$ty> for ProglessInner {
 			#[inline]
 			fn from(total: $ty) -> Self {
-				Self {
-					flags: AtomicU8::new(TICK_NEW),
-					done_total: AtomicU64::new(u64::from(total.get())),
-					..Self::default()
-				}
+				Self::new(TICK_NEW, u64::from(total.get()))
 			}
 		}
 	)+)
@@ -272,11 +420,7 @@ macro_rules! inner_nz_tryfrom {
 			fn try_from(total: $ty) -> Result<Self, Self::Error> {
 				let total = total.get();
 				if total <= 4_294_967_295 {
-					Ok(Self {
-						flags: AtomicU8::new(TICK_NEW),
-						done_total: AtomicU64::new(total as u64),
-						..Self::default()
-					})
+					Ok(Self::new(TICK_NEW, total as u64))
 				}
 				else { Err(ProglessError::TotalOverflow) }
 			}
@@ -329,6 +473,21 @@ impl ProglessInner {
 	/// needed), set "done" equal to "total", and clear any active tasks. It
 	/// will also erase the CLI progress bar from the screen.
 	fn stop(&self) {
+		// If the final frame is to be kept, make sure it is complete before
+		// shutting down.
+		let persist =
+			self.persist.load(SeqCst) &&
+			self.running() &&
+			mutex!(self.renderer).is_none();
+		if persist {
+			let done_total = self.done_total.load(SeqCst);
+			let total = total!(done_total);
+			self.done_total.store(done_total!(total, total), SeqCst);
+			mutex!(self.doing).clear();
+			self.flags.fetch_or(TICK_DONE | TICK_BAR | TICK_DOING, SeqCst);
+			self.tick(true);
+		}
+
 		// Shut 'er down!
 		let flags = self.flags.swap(0, SeqCst);
 		if TICKING == flags & TICKING {
@@ -345,21 +504,85 @@ impl ProglessInner {
 
 			// Freeze the time.
 			self.elapsed.store(
-				u32::saturating_from(self.started.elapsed().as_secs()),
+				u32::saturating_from(self.elapsed().as_secs()),
 				SeqCst
 			);
 
+			// Let the event listener, if any, know.
+			self.emit_progress();
+			self.emit(|| ProglessEvent::Finished { elapsed: self.elapsed() });
+
 			// Clear the tasks.
 			mutex!(self.doing).clear();
 
-			// Clear the screen for good measure.
-			let _res = handle.write_all(CLS).and_then(|()| handle.flush());
+			// Clear the terminal progress, if any.
+			let (term_progress, lines) = {
+				let mut buf = mutex!(self.buf);
+				buf.osc.truncate(0);
+				buf.osc_percent = u8::MAX;
+				(buf.term_progress, buf.lines_printed)
+			};
+
+			// If we were the ones painting, clean up after ourselves.
+			let painter = self.release_painter();
+			if painter {
+				if term_progress {
+					let _res = handle.write_all(OSC_PROGRESS_CLEAR);
+				}
+
+				// Move the cursor below the final frame so it stays put.
+				if persist {
+					for _ in 0..lines { let _res = handle.write_all(b"\n"); }
+					let _res = handle.flush();
+				}
+				// Clear the screen for good measure.
+				else {
+					let _res = handle.write_all(CLS).and_then(|()| handle.flush());
+				}
+			}
+
+			// Don't leave any messages behind, ours or anybody else's.
+			let msgs = self.take_msgs(painter);
+			if ! msgs.is_empty() {
+				let _res = handle.write_all(&msgs).and_then(|()| handle.flush());
+			}
+			drop(handle);
+
+			// Give the custom renderer, if any, one last look.
+			let renderer = mutex!(self.renderer).clone();
+			if let Some(renderer) = renderer { renderer.render(&self.snapshot()); }
 		}
 	}
 }
 
 /// # Getters.
 impl ProglessInner {
+	#[cfg(not(test))]
+	#[inline]
+	/// # Elapsed Time.
+	///
+	/// Return the time elapsed since the instance was created.
+	///
+	/// All timing-related logic should go through this method rather than
+	/// `started` directly so that tests can fast-forward the clock.
+	fn elapsed(&self) -> Duration { self.started.elapsed() }
+
+	#[cfg(test)]
+	/// # Elapsed Time (Testing).
+	///
+	/// Same as the regular version, but with the test-only clock skew added.
+	fn elapsed(&self) -> Duration {
+		self.started.elapsed() + Duration::from_millis(self.skew.load(SeqCst))
+	}
+
+	#[cfg(test)]
+	/// # Fast Forward (Testing).
+	///
+	/// Advance the (fake) clock by the given amount.
+	fn fast_forward(&self, by: Duration) {
+		self.skew.fetch_add(u64::try_from(by.as_millis()).unwrap_or(u64::MAX), SeqCst);
+	}
+
 	#[inline]
 	/// # Is Ticking.
 	///
@@ -369,6 +592,105 @@ impl ProglessInner {
 	/// For the most part, this struct's setter methods only work while
 	/// progress is happening; after that they're frozen.
 	fn running(&self) -> bool { TICKING == self.flags.load(SeqCst) & TICKING }
+
+	/// # Snapshot.
+	///
+	/// Return a copy of the current (structured) state.
+	fn snapshot(&self) -> ProglessSnapshot {
+		let done_total = self.done_total.load(SeqCst);
+		ProglessSnapshot {
+			done: done!(done_total) as u32,
+			total: total!(done_total) as u32,
+			elapsed: self.elapsed(),
+			title: mutex!(self.title).clone(),
+			tasks: mutex!(self.doing).iter().map(ProglessTask::plain).collect(),
+		}
+	}
+}
+
+/// # Events.
+impl ProglessInner {
+	/// # Emit Event.
+	///
+	/// Send an event to the listener, if any. Sends are non-blocking; if the
+	/// channel is full or closed, the event is simply dropped.
+	fn emit<F>(&self, cb: F)
+	where F: FnOnce() -> ProglessEvent {
+		if let Some(tx) = mutex!(self.events).as_ref() {
+			let _res = tx.try_send(cb());
+		}
+	}
+
+	/// # Emit Progress Event.
+	///
+	/// Send a `Progress` event if the done count has changed since the last
+	/// one.
+	fn emit_progress(&self) {
+		let done_total = self.done_total.load(SeqCst);
+		if done_total != self.events_done.swap(done_total, SeqCst) {
+			self.emit(|| ProglessEvent::Progress {
+				done: done!(done_total) as u32,
+				total: total!(done_total) as u32,
+			});
+		}
+	}
+}
+
+/// # Painting.
+impl ProglessInner {
+	#[inline]
+	/// # Painter ID.
+	///
+	/// Return the unique identifier for this instance, for use with the
+	/// global `PAINTER` guard.
+	const fn painter_id(&self) -> usize { self.id }
+
+	/// # Claim Painter.
+	///
+	/// Try to claim exclusive (drawing) access to the screen, returning
+	/// `true` if successful or if it was already ours.
+	///
+	/// When the claim is new, all parts are flagged for redraw to make sure
+	/// the first frame is complete.
+	fn claim_painter(&self) -> bool {
+		let me = self.painter_id();
+		match PAINTER.compare_exchange(0, me, SeqCst, SeqCst) {
+			Ok(_) => {
+				self.flags.fetch_or(TICK_DRAWABLE, SeqCst);
+				true
+			},
+			Err(other) => other == me,
+		}
+	}
+
+	#[inline]
+	/// # Is Painter?
+	///
+	/// Returns `true` if this instance currently holds the screen.
+	fn is_painter(&self) -> bool { PAINTER.load(SeqCst) == self.painter_id() }
+
+	#[inline]
+	/// # Release Painter.
+	///
+	/// Give up exclusive access to the screen, returning `true` if it was
+	/// ours to give.
+	fn release_painter(&self) -> bool {
+		PAINTER.compare_exchange(self.painter_id(), 0, SeqCst, SeqCst).is_ok()
+	}
+
+	/// # Take Messages.
+	///
+	/// Take and return the pending messages, including any handed off by
+	/// other instances if `painter` is `true`.
+	fn take_msgs(&self, painter: bool) -> Vec<u8> {
+		let mut msgs = std::mem::take(&mut *mutex!(self.msgs));
+		if painter {
+			let handoff = std::mem::take(&mut *mutex!(HANDOFF));
+			if msgs.is_empty() { msgs = handoff; }
+			else { msgs.extend_from_slice(&handoff); }
+		}
+		msgs
+	}
 }
 
 /// # Setters.
@@ -380,12 +702,22 @@ impl ProglessInner {
 	/// parallel.
 	///
 	/// Returns `true` if the task was accepted.
-	fn add(&self, txt: &str) -> bool {
-		if
-			self.running() &&
-			ProglessTask::new(txt).is_some_and(|m| mutex!(self.doing).insert(m))
-		{
+	fn add(&self, txt: &str) -> bool { self.add_task(ProglessTask::new(txt)) }
+
+	/// # Add a task (Thread-Tagged).
+	///
+	/// Same as `add`, but with the current thread identified in the label.
+	fn add_on_thread(&self, txt: &str) -> bool {
+		self.add_task(ProglessTask::new_on_thread(txt))
+	}
+
+	/// # Add a (Parsed) Task.
+	fn add_task(&self, task: Option<ProglessTask>) -> bool {
+		let Some(task) = task.filter(|_| self.running()) else { return false; };
+		let name = mutex!(self.events).is_some().then(|| task.plain());
+		if mutex!(self.doing).insert(task) {
 			self.flags.fetch_or(TICK_DOING, SeqCst);
+			if let Some(name) = name { self.emit(|| ProglessEvent::TaskAdded(name)); }
 			true
 		}
 		else { false }
@@ -409,8 +741,59 @@ impl ProglessInner {
 				self.flags.fetch_or(TICK_DONE | TICK_BAR, SeqCst);
 			}
 			// Time to call it quits!
-			else { self.stop(); }
+			else { self.overshoot(done_total); }
+		}
+	}
+
+	/// # Overshoot.
+	///
+	/// This is called when the done count reaches or exceeds the total. By
+	/// default that means it is time to stop, but if overshooting is allowed,
+	/// the done count is simply capped at the total instead.
+	fn overshoot(&self, done_total: u64) {
+		if self.overshoot.load(SeqCst) {
+			let total = total!(done_total);
+			if done!(done_total) != total {
+				self.done_total.store(done_total!(total, total), SeqCst);
+				self.flags.fetch_or(TICK_DONE | TICK_BAR, SeqCst);
+			}
+		}
+		else { self.stop(); }
+	}
+
+	/// # Flush Messages.
+	///
+	/// Write out any pending messages immediately rather than waiting for the
+	/// next tick, then repaint the progress.
+	///
+	/// ## Errors
+	///
+	/// If STDERR cannot be written to, the pending messages are passed back
+	/// as an error in case you want to try to deal with them yourself.
+	fn flush_msgs(&self) -> Result<(), Msg> {
+		let msgs = self.take_msgs(self.is_painter());
+		if msgs.is_empty() { return Ok(()); }
+
+		// Clear the screen, then print the messages.
+		let mut handle = std::io::stderr().lock();
+		let res = handle.write_all(CLS)
+			.and_then(|()| handle.write_all(&msgs))
+			.and_then(|()| handle.flush())
+			.is_err();
+		drop(handle);
+
+		// To complete the illusion, restore the progress bits.
+		self.tick(true);
+
+		// This shouldn't happen.
+		if res {
+			return Err(
+				Msg::plain(String::from_utf8_lossy(msgs.strip_suffix(b"\n").unwrap_or(&msgs)))
+					.with_newline(true)
+			);
 		}
+
+		Ok(())
 	}
 
 	/// # Push Message.
@@ -420,34 +803,35 @@ impl ProglessInner {
 	/// have to wait for the [`Progless`] instance to finish hogging the
 	/// display.
 	///
-	/// ## Errors
+	/// While the progress is on screen, messages are buffered and written
+	/// with the next tick, so rapid-fire pushes are coalesced into a single
+	/// clear and repaint.
 	///
-	/// In practice this should never fail, but if for some reason STDERR is
-	/// tied up the original message is passed back as an error in case you
-	/// want to try to deal with it yourself.
-	fn push_msg(&self, msg: Msg) -> Result<(), Msg> {
+	/// If some other instance is on screen instead, the message is handed off
+	/// to it so as not to scramble its frame.
+	fn push_msg(&self, msg: Msg) {
 		let msg = msg.with_newline(true);
 
-		// If the progress is active, we have to do some things.
-		if self.running() {
-			// Clear the screen, then print the message.
-			let mut handle = std::io::stderr().lock();
-			let res = handle.write_all(CLS)
-				.and_then(|()| handle.write_all(msg.as_bytes()))
-				.and_then(|()| handle.flush())
-				.is_err();
-			drop(handle);
-
-			// To complete the illusion, restore the progress bits.
-			self.tick(true);
+		if self.running() && mutex!(self.renderer).is_none() {
+			// If we're on screen, queue it up for the next tick.
+			if self.is_painter() {
+				mutex!(self.msgs).extend_from_slice(msg.as_bytes());
+				return;
+			}
 
-			// This shouldn't happen.
-			if res { return Err(msg); }
+			// If somebody else is, leave it for them. (The lock is held
+			// through the check so a painter can't slip away in between;
+			// see `stop`.)
+			let mut handoff = mutex!(HANDOFF);
+			if PAINTER.load(SeqCst) != 0 {
+				handoff.extend_from_slice(msg.as_bytes());
+				return;
+			}
+			drop(handoff);
 		}
-		// Otherwise we can just print it directly.
-		else { msg.eprint(); }
 
-		Ok(())
+		// Otherwise we can just print it directly.
+		msg.eprint();
 	}
 
 	/// # Remove a task.
@@ -458,27 +842,47 @@ impl ProglessInner {
 	fn remove(&self, txt: &str) {
 		if self.running() {
 			// Try to remove the task.
-			let removed: bool = {
+			let removed: Option<ProglessTask> = {
 				let txt = txt.trim_end();
 				let mut ptr = mutex!(self.doing);
 
 				// Check for a direct hit first as it is relatively unlikely
 				// the label would have been reformatted for storage.
-				ptr.remove(txt.as_bytes()) ||
-				// Then again, maybe it was…
-				ProglessTask::new(txt).is_some_and(|task|
-					task != *txt && ptr.remove(&task)
+				ptr.take(txt.as_bytes()).or_else(||
+					// Then again, maybe it was…
+					ProglessTask::new(txt)
+						.filter(|task| task != txt)
+						.and_then(|task| ptr.take(&task))
 				)
 			};
 
 			// If we removed an entry, set the tick flag and increment.
-			if removed {
-				self.flags.fetch_or(TICK_DOING, SeqCst);
-				self.increment_n(1);
-			}
+			if let Some(task) = removed { self.removed(&task); }
+		}
+	}
+
+	/// # Remove a task (Thread-Tagged).
+	///
+	/// This is the equal and opposite companion to `add_on_thread`. It must
+	/// be called from the same thread for the labels to match.
+	fn remove_on_thread(&self, txt: &str) {
+		if self.running() {
+			let removed = ProglessTask::new_on_thread(txt)
+				.and_then(|task| mutex!(self.doing).take(&task));
+			if let Some(task) = removed { self.removed(&task); }
 		}
 	}
 
+	/// # Task Removed.
+	///
+	/// Finish up after a task has been removed: set the tick flag, notify the
+	/// event listener, if any, and increment.
+	fn removed(&self, task: &ProglessTask) {
+		self.flags.fetch_or(TICK_DOING, SeqCst);
+		self.emit(|| ProglessEvent::TaskRemoved(task.plain()));
+		self.increment_n(1);
+	}
+
 	/// # Reset.
 	///
 	/// Stop the current run (if any), clear the done/doing metrics, and assign
@@ -498,7 +902,9 @@ impl ProglessInner {
 		if 0 == total { Err(ProglessError::EmptyTotal) }
 		else {
 			self.done_total.store(u64::from(total), SeqCst);
+			self.events_done.store(u64::from(total), SeqCst);
 			self.flags.store(TICK_RESET, SeqCst);
+			self.emit(|| ProglessEvent::Started { total });
 			Ok(())
 		}
 	}
@@ -519,11 +925,145 @@ impl ProglessInner {
 					self.flags.fetch_or(TICK_DONE | TICK_BAR, SeqCst);
 				}
 				// Time to call it quits!
-				else { self.stop(); }
+				else { self.overshoot(done_total); }
+			}
+		}
+	}
+
+	/// # Set Overshoot.
+	///
+	/// Allow or disallow overshooting.
+	fn set_overshoot(&self, allow: bool) {
+		self.overshoot.store(allow, SeqCst);
+	}
+
+	/// # Set Persist on Finish.
+	///
+	/// Keep or erase the final frame.
+	fn set_persist_on_finish(&self, persist: bool) {
+		self.persist.store(persist, SeqCst);
+	}
+
+	/// # Set Event Sender.
+	///
+	/// Set or remove the event channel. If running, a `Started` event (and
+	/// `Progress`, if applicable) is sent right away.
+	fn set_event_sender(&self, tx: Option<SyncSender<ProglessEvent>>) {
+		*mutex!(self.events) = tx;
+		if self.running() {
+			let done_total = self.done_total.load(SeqCst);
+			self.events_done.store(done_total!(0, total!(done_total)), SeqCst);
+			self.emit(|| ProglessEvent::Started { total: total!(done_total) as u32 });
+			self.emit_progress();
+		}
+	}
+
+	/// # Set Renderer.
+	///
+	/// Set or remove the custom renderer.
+	fn set_renderer(&self, renderer: Option<ProglessRenderer>) {
+		*mutex!(self.renderer) = renderer;
+		self.flags.fetch_or(TICK_DRAWABLE, SeqCst);
+	}
+
+	/// # Set Responsive Tasks.
+	///
+	/// Enable or disable height-based task list sizing.
+	fn set_responsive_tasks(&self, responsive: bool) {
+		let mut buf = mutex!(self.buf);
+		if buf.responsive != responsive {
+			buf.responsive = responsive;
+			drop(buf);
+			self.flags.fetch_or(TICK_DOING, SeqCst);
+		}
+	}
+
+	/// # Set Smooth Bars.
+	///
+	/// Enable or disable smooth (block-based) bar rendering.
+	fn set_smooth_bars(&self, smooth: bool) {
+		let mut buf = mutex!(self.buf);
+		if buf.smooth != smooth {
+			buf.smooth = smooth;
+			drop(buf);
+			self.flags.fetch_or(TICK_BAR, SeqCst);
+		}
+	}
+
+	/// # Set Accent.
+	///
+	/// Change the accent color.
+	fn set_accent(&self, color: u8) {
+		mutex!(self.buf).set_accent(color);
+		self.flags.fetch_or(TICK_BAR, SeqCst);
+	}
+
+	/// # Set Compact.
+	///
+	/// Enable or disable compact (percentage-only) display.
+	fn set_compact(&self, compact: bool) {
+		let mut buf = mutex!(self.buf);
+		if buf.compact != compact {
+			buf.compact = compact;
+			drop(buf);
+			self.flags.fetch_or(TICK_BAR, SeqCst);
+		}
+	}
+
+	/// # Set Max Visible Tasks.
+	///
+	/// Cap the number of task lines displayed. Zero means no limit.
+	fn set_max_visible_tasks(&self, max: u8) {
+		let mut buf = mutex!(self.buf);
+		if buf.max_doing != max {
+			buf.max_doing = max;
+			drop(buf);
+			self.flags.fetch_or(TICK_DOING, SeqCst);
+		}
+	}
+
+	/// # Set Tasks.
+	///
+	/// Replace the active task list wholesale. Unlike `remove`, this has no
+	/// effect on the done count.
+	fn set_tasks(&self, tasks: BTreeSet<ProglessTask>) {
+		if self.running() {
+			let mut doing = mutex!(self.doing);
+			if *doing != tasks {
+				*doing = tasks;
+				drop(doing);
+				self.flags.fetch_or(TICK_DOING, SeqCst);
 			}
 		}
 	}
 
+	/// # Set Template.
+	///
+	/// Set or remove the custom layout.
+	fn set_template(&self, template: Option<ProglessTemplate>) {
+		let mut buf = mutex!(self.buf);
+		if buf.template != template {
+			buf.template = template;
+			drop(buf);
+			self.flags.fetch_or(TICK_RESIZED, SeqCst);
+		}
+	}
+
+	/// # Set Terminal Progress.
+	///
+	/// Enable or disable OSC 9;4 terminal progress reporting.
+	fn set_terminal_progress(&self, enabled: bool) {
+		let mut buf = mutex!(self.buf);
+		if buf.term_progress != enabled {
+			buf.term_progress = enabled;
+			buf.osc_percent = u8::MAX;
+			buf.osc.truncate(0);
+			if ! enabled { buf.osc.extend_from_slice(OSC_PROGRESS_CLEAR); }
+			drop(buf);
+			self.flags.fetch_or(TICK_PERCENT, SeqCst);
+		}
+	}
+
 	/// # Set Title.
 	///
 	/// Give the progress bar a title, which will be shown above the progress
@@ -575,10 +1115,35 @@ impl ProglessInner {
 		// We aren't running!
 		if ! self.running() { return false; }
 
+		// Keep the event listener, if any, in the loop.
+		self.emit_progress();
+
+		// If there's a custom renderer, let it handle the "drawing".
+		let renderer = mutex!(self.renderer).clone();
+		if let Some(renderer) = renderer {
+			if self.tick_set_drawable().is_some() || force {
+				renderer.render(&self.snapshot());
+			}
+			return true;
+		}
+
 		// Lock STDERR as early as possible to keep the state as consistent as
 		// possible, even though we may well not end up using it.
 		let mut handle = std::io::stderr().lock();
 
+		// Print any pending messages straight away, even if nothing else can
+		// be drawn this time around. The progress bits they clobbered will
+		// need to be repainted.
+		if self.is_painter() {
+			let msgs = self.take_msgs(true);
+			if ! msgs.is_empty() {
+				let _res = handle.write_all(CLS)
+					.and_then(|()| handle.write_all(&msgs))
+					.and_then(|()| handle.flush());
+				self.flags.fetch_or(TICK_DRAWABLE, SeqCst);
+			}
+		}
+
 		// Pull the terminal dimensions.
 		let Some((width, height)) = self.tick_set_size() else {
 			// The size either changed between ticks or cannot be determined.
@@ -587,6 +1152,9 @@ impl ProglessInner {
 			return true;
 		};
 
+		// If some other instance is painting, we have to wait our turn.
+		if ! self.claim_painter() { return true; }
+
 		// If we don't even have enough space for a percentage, clear the
 		// screen and call it a day.
 		if width.get() < MIN_DRAW_WIDTH {
@@ -599,8 +1167,8 @@ impl ProglessInner {
 			// Yes!
 			Some(t) => t,
 			None =>
-				// No, but it doesn't matter; "push_msg" cleared the screen so
-				// a repaint is required.
+				// No, but it doesn't matter; "flush_msgs" cleared the screen
+				// so a repaint is required.
 				if force { 0 }
 				// No, and there's nothing for us to do.
 				else { return true; },
@@ -634,6 +1202,7 @@ impl ProglessInner {
 					else if done >= total { 1.0 }
 					else { (f64::from(done) / f64::from(total)) as f32 };
 				buf.percent.replace(percent);
+				if buf.term_progress { buf.set_osc(percent); }
 			}
 
 			// The bar formatting depends on both the values and sizing of the
@@ -664,8 +1233,18 @@ impl ProglessInner {
 			buf.set_doing(&mutex!(self.doing), width, height);
 		}
 
+		// Templated layouts are assembled from the other parts, so need to be
+		// rebuilt after any of them change.
+		if buf.template.is_some() {
+			let done_total = self.done_total.load(SeqCst);
+			let done = done!(done_total) as u32;
+			let total = total!(done_total) as u32;
+			buf.set_line(width, done, total, mutex!(self.title).as_ref());
+		}
+
 		// We made it! Print and return.
 		buf.print(width, &mut handle);
+		buf.osc.truncate(0);
 		true
 	}
 
@@ -699,7 +1278,7 @@ impl ProglessInner {
 	/// formatted values.
 	fn tick_set_secs(&self) -> bool {
 		// No change to the seconds bit.
-		let secs: u32 = u32::saturating_from(self.started.elapsed().as_secs());
+		let secs: u32 = u32::saturating_from(self.elapsed().as_secs());
 		if secs == self.elapsed.swap(secs, SeqCst) { false }
 		else {
 			mutex!(self.buf).elapsed.replace(secs);
@@ -760,6 +1339,7 @@ impl ProglessInner {
 
 
 
+#[expect(clippy::struct_excessive_bools, reason = "They're display options, not states.")]
 #[derive(Debug)]
 /// # Progless Output Buffers.
 ///
@@ -778,6 +1358,11 @@ struct ProglessBuffer {
 	/// # The "Done" Part of the Bar.
 	bar_done: &'static [u8],
 
+	/// # The Partially-Done Cell of the Bar.
+	///
+	/// This is only used for smooth bars.
+	bar_partial: &'static [u8],
+
 	/// # The "TBD" Part of the Bar.
 	bar_undone: &'static [u8],
 
@@ -795,6 +1380,70 @@ struct ProglessBuffer {
 
 	/// # Task Lines.
 	lines_doing: u8,
+
+	/// # Printed Lines.
+	///
+	/// The total number of lines occupied by the last print.
+	lines_printed: u8,
+
+	/// # Max Visible Tasks.
+	///
+	/// The maximum number of task lines to display, or zero for no limit.
+	max_doing: u8,
+
+	/// # Responsive Tasks.
+	///
+	/// Show as many tasks as the screen height allows — up to `max_doing` —
+	/// rather than all or nothing.
+	responsive: bool,
+
+	/// # Smooth Bars.
+	///
+	/// Draw the bar with block characters, including a partial cell for the
+	/// fractional remainder.
+	smooth: bool,
+
+	/// # Compact.
+	///
+	/// Print just the percentage, regardless of the screen width.
+	compact: bool,
+
+	/// # Accent (Done).
+	///
+	/// The ANSI sequence used for the "done" bits — prefix, bar, and count.
+	accent_done: Cow<'static, [u8]>,
+
+	/// # Accent (Undone).
+	///
+	/// The ANSI sequence used for the "undone" bits — bar and total.
+	accent_undone: Cow<'static, [u8]>,
+
+	/// # Template.
+	///
+	/// A custom layout for the main progress line, if any.
+	template: Option<ProglessTemplate>,
+
+	/// # Templated Line.
+	///
+	/// The main progress line, assembled according to the template.
+	line: Vec<u8>,
+
+	/// # Terminal Progress.
+	///
+	/// Emit OSC 9;4 sequences so the terminal can display its own native
+	/// progress indicator (in the taskbar, dock, etc.).
+	term_progress: bool,
+
+	/// # Terminal Progress Sequence.
+	///
+	/// This holds the pending OSC 9;4 sequence, if any. It is cleared after
+	/// each print.
+	osc: Vec<u8>,
+
+	/// # Terminal Progress Percent.
+	///
+	/// The last (whole) percentage sent to the terminal.
+	osc_percent: u8,
 }
 
 impl ProglessBuffer {
@@ -803,12 +1452,25 @@ impl ProglessBuffer {
 		title: Vec::new(),
 		elapsed: NiceClock::MIN,
 		bar_done: &[],
+		bar_partial: &[],
 		bar_undone: &[],
 		done: NiceU32::MIN,
 		total: NiceU32::MIN,
 		percent: NicePercent::MIN,
 		doing: Vec::new(),
 		lines_doing: 0,
+		lines_printed: 0,
+		max_doing: 0,
+		responsive: false,
+		smooth: false,
+		compact: false,
+		accent_done: Cow::Borrowed(b"\x1b[0;1;96m"),
+		accent_undone: Cow::Borrowed(b"\x1b[0;1;34m"),
+		template: None,
+		line: Vec::new(),
+		term_progress: false,
+		osc: Vec::new(),
+		osc_percent: u8::MAX,
 	};
 }
 
@@ -818,7 +1480,7 @@ impl ProglessBuffer {
 	///
 	/// This writes the fully-formatted progress data to STDERR, returning the
 	/// status as a bool.
-	fn print(&self, width: NonZeroU8, handle: &mut StderrLock<'static>) -> bool {
+	fn print(&mut self, width: NonZeroU8, handle: &mut StderrLock<'static>) -> bool {
 		use std::io::ErrorKind;
 
 		/// # Progress Output Closer.
@@ -843,26 +1505,51 @@ impl ProglessBuffer {
 			b"\x1b[0m\r\x1b[240A", b"\x1b[0m\r\x1b[241A", b"\x1b[0m\r\x1b[242A", b"\x1b[0m\r\x1b[243A", b"\x1b[0m\r\x1b[244A", b"\x1b[0m\r\x1b[245A", b"\x1b[0m\r\x1b[246A", b"\x1b[0m\r\x1b[247A", b"\x1b[0m\r\x1b[248A", b"\x1b[0m\r\x1b[249A", b"\x1b[0m\r\x1b[250A", b"\x1b[0m\r\x1b[251A", b"\x1b[0m\r\x1b[252A", b"\x1b[0m\r\x1b[253A", b"\x1b[0m\r\x1b[254A", b"\x1b[0m\r\x1b[255A",
 		];
 
+		// Note how many lines the frame will occupy.
+		self.lines_printed =
+			if self.compact || width.get() < 40 { 1 }
+			else if self.title.is_empty() { self.lines_doing.saturating_add(1) }
+			else { self.lines_doing.saturating_add(2) };
+
+		// The number of lines we'll need to move up after printing to get back
+		// to the start.
+		let lines =
+			if self.title.is_empty() { self.lines_doing }
+			else { self.lines_doing.saturating_add(1) };
+
 		// We're discontiguous enough to warrant vectored writes, I think…
 		let mut parts: &mut [IoSlice] =
-			// If the screen is too small for everything, print the percentage
-			// by itself to give them some indication of progress.
-			if width.get() < 40 {
+			// If the screen is too small for everything — or compact mode is
+			// enabled — print the percentage by itself to give them some
+			// indication of progress.
+			if self.compact || width.get() < 40 {
 				&mut [
-					IoSlice::new("\x1b[J \x1b[0;1;96m» \x1b[0;1m".as_bytes()), // Clear + Prefix.
+					IoSlice::new(&self.osc), // Terminal progress.
+					IoSlice::new(b"\x1b[J "), // Clear.
+					IoSlice::new(&self.accent_done),
+					IoSlice::new("» \x1b[0;1m".as_bytes()), // Prefix.
 					IoSlice::new(self.percent.as_bytes()), // Percent.
 					IoSlice::new(b"\x1b[0m\r"), // Reset and rewind.
 				]
 			}
-			// Otherwise give it all we've got!
+			// Custom layouts are pre-assembled.
+			else if self.template.is_some() {
+				&mut [
+					IoSlice::new(&self.osc), // Terminal progress.
+					IoSlice::new(CLS), // Clear.
+					IoSlice::new(&self.title), // Title.
+					IoSlice::new(&self.line), // Progress.
+					IoSlice::new(b"\x1b[0;35m"), // Tasks.
+					IoSlice::new(&self.doing),
+					IoSlice::new(CLOSE[usize::from(lines)]), // The end!
+				]
+			}
+			// Otherwise give it all we've got!
 			else {
-				// The number of lines we'll need to move up after printing to
-				// get back to the start.
-				let lines =
-					if self.title.is_empty() { self.lines_doing }
-					else { self.lines_doing.saturating_add(1) };
-
 				&mut [
+					// Terminal progress.
+					IoSlice::new(&self.osc),
+
 					// Clear.
 					IoSlice::new(CLS),
 
@@ -872,17 +1559,21 @@ impl ProglessBuffer {
 					// Elapsed.
 					IoSlice::new(b"\x1b[0;2m[\x1b[0;1m"),
 					IoSlice::new(self.elapsed.as_bytes()),
-					IoSlice::new(b"\x1b[0;2m]  [\x1b[0;1;96m"),
+					IoSlice::new(b"\x1b[0;2m]  ["),
 
 					// Bars.
+					IoSlice::new(&self.accent_done),
 					IoSlice::new(self.bar_done),
-					IoSlice::new(b"\x1b[0;1;34m"),
+					IoSlice::new(self.bar_partial),
+					IoSlice::new(&self.accent_undone),
 					IoSlice::new(self.bar_undone),
-					IoSlice::new(b"\x1b[0;2m]\x1b[0;1;96m  "),
+					IoSlice::new(b"\x1b[0;2m]  "),
 
 					// Done/total.
+					IoSlice::new(&self.accent_done),
 					IoSlice::new(self.done.as_bytes()),
-					IoSlice::new(b"\x1b[0;2m/\x1b[0;1;34m"),
+					IoSlice::new(b"\x1b[0;2m/"),
+					IoSlice::new(&self.accent_undone),
 					IoSlice::new(self.total.as_bytes()),
 
 					// Percent.
@@ -915,12 +1606,28 @@ impl ProglessBuffer {
 }
 
 impl ProglessBuffer {
+	/// # Set Accent.
+	///
+	/// Rebuild the accent sequences using the given ANSI-256 color.
+	fn set_accent(&mut self, color: u8) {
+		let color = NiceU8::from(color);
+
+		let mut done = Vec::with_capacity(15);
+		done.extend_from_slice(b"\x1b[0;1;38;5;");
+		done.extend_from_slice(color.as_bytes());
+		done.push(b'm');
+
+		let mut undone = Vec::with_capacity(15);
+		undone.extend_from_slice(b"\x1b[0;2;38;5;");
+		undone.extend_from_slice(color.as_bytes());
+		undone.push(b'm');
+
+		self.accent_done = Cow::Owned(done);
+		self.accent_undone = Cow::Owned(undone);
+	}
+
 	/// # Set Bars.
 	fn set_bars(&mut self, width: NonZeroU8, done: u32, total: u32) {
-		// Default sizes.
-		let mut w_done = 0_u8;
-		let mut w_undone = 0_u8;
-
 		// How much room do we have for the bar(s)?
 		// The magic "19" is made up of the following hard-coded pieces:
 		// 10: elapsed time and braces;
@@ -936,12 +1643,32 @@ impl ProglessBuffer {
 			self.percent.len()
 		));
 
+		self.set_bars_space(space, done, total);
+	}
+
+	/// # Set Bars (Fixed Space).
+	///
+	/// Divide up the given space between the done and undone parts of the
+	/// bar.
+	fn set_bars_space(&mut self, space: u8, done: u32, total: u32) {
+		// Default sizes.
+		let mut w_done = 0_u8;
+		let mut w_partial = 0_u8;
+		let mut w_undone = 0_u8;
+
 		// If we have any space, divide it up proportionately.
 		if total != 0 && MIN_BARS_WIDTH <= space {
 			// Nothing is done.
 			if done == 0 { w_undone = space; }
 			// Everything is done!
 			else if done == total { w_done = space; }
+			// Working on it, smoothly!
+			else if self.smooth {
+				let eighths = u64::from(done) * u64::from(space) * 8 / u64::from(total);
+				w_done = u8::saturating_from(eighths / 8);
+				w_partial = (eighths % 8) as u8;
+				w_undone = space.saturating_sub(w_done + u8::from(w_partial != 0));
+			}
 			// Working on it!
 			else {
 				w_done = u8::saturating_from((done * u32::from(space)).wrapping_div(total));
@@ -949,17 +1676,37 @@ impl ProglessBuffer {
 			}
 
 			debug_assert_eq!(
-				w_done + w_undone,
+				w_done + u8::from(w_partial != 0) + w_undone,
 				space,
 				"BUG: bar space was miscalculated."
 			);
 		}
 
 		// Update the parts!.
-		self.bar_done =     &BAR_DONE[..usize::from(w_done)];
+		self.bar_done =
+			if self.smooth { &BAR_DONE_SMOOTH[..usize::from(w_done) * 3] }
+			else { &BAR_DONE[..usize::from(w_done)] };
+		self.bar_partial = BAR_PARTIAL[usize::from(w_partial)];
 		self.bar_undone = &BAR_UNDONE[..usize::from(w_undone)];
 	}
 
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	#[expect(clippy::cast_sign_loss, reason = "False positive.")]
+	/// # Update Terminal Progress.
+	///
+	/// Queue up an OSC 9;4 progress sequence if the (whole) percentage has
+	/// changed since the last time.
+	fn set_osc(&mut self, percent: f32) {
+		let percent = (percent.clamp(0.0, 1.0) * 100.0) as u8;
+		if percent != self.osc_percent {
+			self.osc_percent = percent;
+			self.osc.truncate(0);
+			self.osc.extend_from_slice(b"\x1b]9;4;1;");
+			self.osc.extend_from_slice(NiceU8::from(percent).as_bytes());
+			self.osc.push(b'\x07');
+		}
+	}
+
 	/// # Update Tasks.
 	fn set_doing(
 		&mut self,
@@ -980,24 +1727,142 @@ impl ProglessBuffer {
 		// the prefix.
 		let width = usize::from(width.get().saturating_sub(12));
 
+		// If there's a cap, figure out how many tasks we'll actually show,
+		// and how many will be summarized instead.
+		let (mut visible, mut hidden) =
+			if self.max_doing == 0 || doing.len() <= usize::from(self.max_doing) {
+				(doing.len(), 0)
+			}
+			else { (usize::from(self.max_doing), doing.len() - usize::from(self.max_doing)) };
+
+		// If responsive, shrink the list as needed to fit the screen, leaving
+		// room for the summary line.
+		if self.responsive {
+			let room = usize::from(height.get())
+				.saturating_sub(usize::from(! self.title.is_empty()) + 1);
+			if room < visible + usize::from(hidden != 0) {
+				visible = room.saturating_sub(1);
+				hidden = doing.len() - visible;
+			}
+		}
+
 		// Add each task as its own line, assuming we have the room.
 		if
 			2 <= width &&
-			usize::from(! self.title.is_empty()) + 1 + doing.len() <= usize::from(height.get())
+			usize::from(! self.title.is_empty()) + 1 + visible + usize::from(hidden != 0) <= usize::from(height.get())
 		{
-			for line in doing.iter().filter_map(|line| line.fitted(width)) {
+			for line in doing.iter().take(visible).filter_map(|line| line.fitted(width)) {
 				self.doing.extend_from_slice(PREFIX);
 				self.doing.extend_from_slice(line);
 				self.lines_doing += 1;
 			}
+
+			// Summarize the rest, if any, so long as it fits.
+			if hidden != 0 {
+				let hidden = NiceU32::from(u32::saturating_from(hidden));
+				if hidden.len() + 11 <= width {
+					self.doing.extend_from_slice(PREFIX);
+					self.doing.extend_from_slice("\x1b[2m… and ".as_bytes());
+					self.doing.extend_from_slice(hidden.as_bytes());
+					self.doing.extend_from_slice(b" more");
+					self.lines_doing += 1;
+				}
+			}
 		}
 	}
 
+	/// # Update Templated Line.
+	///
+	/// Assemble the main progress line according to the template, sizing the
+	/// bar (and inline title, if any) to fit whatever space is left over.
+	fn set_line(&mut self, width: NonZeroU8, done: u32, total: u32, title: Option<&Msg>) {
+		self.line.truncate(0);
+		let Some(template) = self.template.take() else { return; };
+
+		// Add up the fixed-width bits.
+		let mut space = template.literal_width();
+		for token in template.tokens() {
+			match token {
+				ProglessToken::Elapsed => { space += self.elapsed.as_bytes().len(); },
+				ProglessToken::Pos => { space += self.done.len(); },
+				ProglessToken::Len => { space += self.total.len(); },
+				ProglessToken::Percent => { space += self.percent.len(); },
+				_ => {},
+			}
+		}
+		let mut space = usize::from(width.get()).saturating_sub(space);
+
+		// The title gets whatever's left, minus room for a minimal bar.
+		let fitted = title.filter(|_| template.has_msg()).map(|title| {
+			let bars = if template.has_bar() { usize::from(MIN_BARS_WIDTH) } else { 0 };
+			title.fitted(space.saturating_sub(bars))
+		});
+		let mut msg: &[u8] = fitted.as_deref().unwrap_or_default();
+		if let Some(end) = msg.iter().position(|&b| b == b'\n') { msg = &msg[..end]; }
+		space = space.saturating_sub(crate::width(msg));
+
+		// The bar gets the rest.
+		if template.has_bar() {
+			self.set_bars_space(u8::saturating_from(space), done, total);
+		}
+
+		// Put it all together!
+		for token in template.tokens() {
+			match token {
+				ProglessToken::Literal(s) => {
+					self.line.extend_from_slice(b"\x1b[0;2m");
+					self.line.extend_from_slice(s.as_bytes());
+				},
+				ProglessToken::Elapsed => {
+					self.line.extend_from_slice(b"\x1b[0;1m");
+					self.line.extend_from_slice(self.elapsed.as_bytes());
+				},
+				ProglessToken::Bar => {
+					self.line.extend_from_slice(&self.accent_done);
+					self.line.extend_from_slice(self.bar_done);
+					self.line.extend_from_slice(self.bar_partial);
+					self.line.extend_from_slice(&self.accent_undone);
+					self.line.extend_from_slice(self.bar_undone);
+				},
+				ProglessToken::Pos => {
+					self.line.extend_from_slice(&self.accent_done);
+					self.line.extend_from_slice(self.done.as_bytes());
+				},
+				ProglessToken::Len => {
+					self.line.extend_from_slice(&self.accent_undone);
+					self.line.extend_from_slice(self.total.as_bytes());
+				},
+				ProglessToken::Percent => {
+					self.line.extend_from_slice(b"\x1b[0;1m");
+					self.line.extend_from_slice(self.percent.as_bytes());
+				},
+				ProglessToken::Msg => {
+					self.line.extend_from_slice(b"\x1b[0m");
+					self.line.extend_from_slice(msg);
+				},
+			}
+		}
+
+		// If the fixed-width bits alone are too wide for the screen, chop the
+		// line down to size so it doesn't wrap and throw off the cursor math.
+		let width = usize::from(width.get());
+		if width < crate::width(&self.line) {
+			let end = crate::length_width(&self.line, width);
+			self.line.truncate(end);
+			self.line.extend_from_slice(b"\x1b[0m");
+		}
+
+		self.template = Some(template);
+	}
+
 	/// # Update Title.
 	fn set_title(&mut self, title: Option<&Msg>, width: NonZeroU8, height: NonZeroU8) {
 		// Reset the title.
 		self.title.truncate(0);
 
+		// Templates with a {msg} placeholder print the title inline.
+		if self.template.as_ref().is_some_and(ProglessTemplate::has_msg) { return; }
+
 		// We need at least two lines of screen space to fit a title.
 		if 2 <= height.get() {
 			if let Some(title) = title {
@@ -1082,6 +1947,12 @@ impl ProglessBuffer {
 ///
 /// // ... snip
 /// ```
+///
+/// ## Multiple Instances
+///
+/// Only one `Progless` can draw to the screen at a time. If several are
+/// running concurrently, the first to tick gets the screen, and the others
+/// keep counting quietly in the background until it finishes (or is dropped).
 pub struct Progless {
 	/// # Steady Ticker.
 	steady: Arc<ProglessSteady>,
@@ -1111,7 +1982,7 @@ impl From<Progless> for Msg {
 	///
 	/// For a more advanced summary, use the [`Progless::summary`] method.
 	fn from(src: Progless) -> Self {
-		let elapsed = NiceElapsed::from(src.inner.started);
+		let elapsed = NiceElapsed::from(src.inner.elapsed());
 		let mut msg = String::with_capacity(13 + elapsed.len());
 		msg.push_str("Finished in ");
 		msg.push_str(elapsed.as_str());
@@ -1270,6 +2141,331 @@ impl Progless {
 		self
 	}
 
+	#[must_use]
+	#[inline]
+	/// # With Accent Color.
+	///
+	/// Recolor the progress bar using the given ANSI-256 color, for tools
+	/// wanting to match a particular brand or theme.
+	///
+	/// By default, the "done" parts — the prefix, bar, and count — are light
+	/// cyan, while the "undone" parts — the rest of the bar and the total —
+	/// are blue. With an accent, the former are drawn in bold, and the latter
+	/// dimmed.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Progless;
+	///
+	/// // Orange!
+	/// let pbar = Progless::try_from(1001_u32).unwrap()
+	///     .with_accent(208);
+	/// ```
+	pub fn with_accent(self, color: u8) -> Self {
+		self.inner.set_accent(color);
+		self
+	}
+
+	#[must_use]
+	#[inline]
+	/// # With Compact Display.
+	///
+	/// When enabled, only the percentage — `» 42%` — is displayed, as is
+	/// normally the case for narrow (sub-40-column) screens, regardless of
+	/// the actual screen width.
+	///
+	/// The title, elapsed time, bar, counts, and task list are all omitted.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Progless;
+	///
+	/// let pbar = Progless::try_from(1001_u32).unwrap()
+	///     .with_compact(true);
+	/// ```
+	pub fn with_compact(self, compact: bool) -> Self {
+		self.inner.set_compact(compact);
+		self
+	}
+
+	#[must_use]
+	#[inline]
+	/// # With Max Visible Tasks.
+	///
+	/// When [`Progless::add`]/[`Progless::remove`] are used to track active
+	/// tasks, each one is printed on its own line below the bar, so long as
+	/// the terminal is tall enough.
+	///
+	/// For highly parallel workloads, that can be a lot of lines. Use this
+	/// method to cap the number of task lines displayed; any extras will be
+	/// summarized with a single "… and N more" line instead.
+	///
+	/// Pass `0` to remove the limit (the default).
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Progless;
+	///
+	/// // Show no more than five tasks at a time.
+	/// let pbar = Progless::try_from(1001_u32).unwrap()
+	///     .with_max_visible_tasks(5);
+	/// ```
+	pub fn with_max_visible_tasks(self, max: u8) -> Self {
+		self.inner.set_max_visible_tasks(max);
+		self
+	}
+
+	#[must_use]
+	#[inline]
+	/// # With Overshoot.
+	///
+	/// By default, progress automatically finishes — and the bar vanishes —
+	/// as soon as the done count reaches the total, whether via
+	/// [`Progless::increment`], [`Progless::remove`], or [`Progless::set_done`].
+	///
+	/// If your done counts are approximate, or come from parallel workers
+	/// that might briefly report more than the total, that can be a bit
+	/// surprising. Enabling this option changes the semantics: the done count
+	/// is instead capped at the total — the display sitting at 100% — and the
+	/// bar remains up until [`Progless::finish`] is called.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Progless;
+	///
+	/// let pbar = Progless::try_from(1001_u32).unwrap()
+	///     .with_overshoot(true);
+	///
+	/// // This won't stop anything.
+	/// pbar.set_done(2000);
+	/// assert_eq!(pbar.done(), 1001);
+	///
+	/// // This will.
+	/// pbar.finish();
+	/// ```
+	pub fn with_overshoot(self, allow: bool) -> Self {
+		self.inner.set_overshoot(allow);
+		self
+	}
+
+	#[must_use]
+	#[inline]
+	/// # With Persist on Finish.
+	///
+	/// By default, the progress bar is erased from the screen once progress
+	/// finishes. Enable this option to leave the final (100%) frame in place
+	/// instead, as a visible record of what was completed.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Progless;
+	///
+	/// let pbar = Progless::try_from(1001_u32).unwrap()
+	///     .with_persist_on_finish(true);
+	/// ```
+	pub fn with_persist_on_finish(self, persist: bool) -> Self {
+		self.inner.set_persist_on_finish(persist);
+		self
+	}
+
+	#[must_use]
+	#[inline]
+	/// # With Event Sender.
+	///
+	/// Send structured [`ProglessEvent`]s — start, progress, task changes,
+	/// and finish — to the given channel, alongside the usual display, for
+	/// forwarding to a web UI, metrics system, etc.
+	///
+	/// Progress events are checked once per tick, so rapid changes to the
+	/// done count are coalesced, much like the display itself.
+	///
+	/// Sends are non-blocking; if the channel fills up, events are dropped
+	/// rather than stalling the work, so size the buffer accordingly.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::{Progless, ProglessEvent};
+	/// use std::sync::mpsc::sync_channel;
+	///
+	/// let (tx, rx) = sync_channel(256);
+	/// let pbar = Progless::try_from(1001_u32).unwrap()
+	///     .with_event_sender(tx);
+	///
+	/// std::thread::spawn(move || {
+	///     for event in rx {
+	///         if let ProglessEvent::Progress { done, total } = event {
+	///             println!("{done}/{total}");
+	///         }
+	///     }
+	/// });
+	/// ```
+	pub fn with_event_sender(self, tx: SyncSender<ProglessEvent>) -> Self {
+		self.inner.set_event_sender(Some(tx));
+		self
+	}
+
+	#[must_use]
+	#[inline]
+	/// # With Custom Renderer.
+	///
+	/// By default, [`Progless`] paints itself to STDERR. If you'd rather draw
+	/// the progress yourself — as part of a larger TUI dashboard, for
+	/// example — use this method to supply a callback instead.
+	///
+	/// Once set, nothing will be painted to the terminal; the callback will
+	/// instead be passed a [`ProglessSnapshot`] with the structured state
+	/// whenever something changes (checked every tick), plus once more when
+	/// progress finishes.
+	///
+	/// Note: the callback is run from the steady ticker's thread, so should
+	/// return quickly.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Progless;
+	///
+	/// let pbar = Progless::try_from(1001_u32).unwrap()
+	///     .with_renderer(|snap| {
+	///         // Draw it however you like!
+	///         println!("{}/{}", snap.done(), snap.total());
+	///     });
+	/// ```
+	pub fn with_renderer<F>(self, cb: F) -> Self
+	where F: Fn(&ProglessSnapshot) + Send + Sync + 'static {
+		self.inner.set_renderer(Some(ProglessRenderer::from(cb)));
+		self
+	}
+
+	#[must_use]
+	#[inline]
+	/// # With Responsive Tasks.
+	///
+	/// By default, the active task list is shown in full — or up to the
+	/// [`Progless::with_max_visible_tasks`] limit — if it fits the screen,
+	/// and not at all if it doesn't.
+	///
+	/// When enabled, the list is instead sized to fit the available rows,
+	/// with any overflow summarized as "… and N more". It grows and shrinks
+	/// automatically as the terminal is resized, still capped by the max,
+	/// if any.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Progless;
+	///
+	/// let pbar = Progless::try_from(1001_u32).unwrap()
+	///     .with_responsive_tasks(true);
+	/// ```
+	pub fn with_responsive_tasks(self, responsive: bool) -> Self {
+		self.inner.set_responsive_tasks(responsive);
+		self
+	}
+
+	#[must_use]
+	#[inline]
+	/// # With Smooth Bars.
+	///
+	/// By default, the progress bar is drawn with whole `#` and `-` cells,
+	/// which can look a bit chunky when progress is slow and the bar is wide.
+	///
+	/// Enabling this option draws the "done" portion with Unicode blocks
+	/// instead, using the block-eighths (`▏▎▍▌▋▊▉`) to represent the
+	/// fractional remainder, for smoother animation.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Progless;
+	///
+	/// let pbar = Progless::try_from(1001_u32).unwrap()
+	///     .with_smooth_bars(true);
+	/// ```
+	pub fn with_smooth_bars(self, smooth: bool) -> Self {
+		self.inner.set_smooth_bars(smooth);
+		self
+	}
+
+	/// # With Template.
+	///
+	/// Replace the default layout of the main progress line — elapsed time,
+	/// bar, done/total, and percentage — with a custom one, e.g. to match the
+	/// look of other tools.
+	///
+	/// Templates are plain text with any of the following placeholders mixed
+	/// in:
+	///
+	/// | Placeholder | Value |
+	/// | ----------- | ----- |
+	/// | `{elapsed}` | The elapsed time, `HH:MM:SS`. |
+	/// | `{bar}` | The progress bar itself, sized to fill the available space. |
+	/// | `{pos}` | The number of tasks done. |
+	/// | `{len}` | The total number of tasks. |
+	/// | `{percent}` | The percentage done. |
+	/// | `{msg}` | The title, if any, which will be printed inline instead of on its own line. |
+	///
+	/// The bar and title may each appear at most once. Literal braces can be
+	/// escaped by doubling them, i.e. `{{` and `}}`.
+	///
+	/// Compact mode and narrow screens still print the percentage by itself,
+	/// and active tasks, if any, are still listed below.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Progless;
+	///
+	/// let pbar = Progless::try_from(1001_u32).unwrap()
+	///     .with_template("{msg} {percent} [{bar}] {pos}/{len} ({elapsed})")
+	///     .unwrap();
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// An error will be returned if the template is empty, spans multiple
+	/// lines, contains unrecognized or unbalanced placeholders, or repeats
+	/// `{bar}` or `{msg}`.
+	pub fn with_template(self, template: &str) -> Result<Self, ProglessError> {
+		let template = ProglessTemplate::try_from(template)?;
+		self.inner.set_template(Some(template));
+		Ok(self)
+	}
+
+	#[must_use]
+	#[inline]
+	/// # With Terminal Progress.
+	///
+	/// Many modern terminals — Windows Terminal, some VTE-based emulators,
+	/// etc. — can display a native progress indicator in the taskbar or dock
+	/// when sent the appropriate OSC 9;4 escape sequences.
+	///
+	/// Enabling this option will emit such sequences alongside the regular
+	/// bar whenever the (whole) percentage changes, and clear the indicator
+	/// once progress has finished.
+	///
+	/// Terminals without support for the sequences should simply ignore
+	/// them.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Progless;
+	///
+	/// let pbar = Progless::try_from(1001_u32).unwrap()
+	///     .with_terminal_progress(true);
+	/// ```
+	pub fn with_terminal_progress(self, enabled: bool) -> Self {
+		self.inner.set_terminal_progress(enabled);
+		self
+	}
+
 	#[expect(clippy::must_use_candidate, reason = "Caller might not care.")]
 	#[inline]
 	/// # Stop.
@@ -1278,7 +2474,8 @@ impl Progless {
 	/// time elapsed.
 	///
 	/// Calling this method will also erase any previously-printed progress
-	/// information from the CLI screen.
+	/// information from the CLI screen, unless [`Progless::with_persist_on_finish`]
+	/// is enabled.
 	///
 	///
 	/// ## Examples
@@ -1304,7 +2501,7 @@ impl Progless {
 	pub fn finish(&self) -> Duration {
 		self.inner.stop();
 		self.steady.stop();
-		self.inner.started.elapsed()
+		self.inner.elapsed()
 	}
 
 	#[must_use]
@@ -1348,12 +2545,137 @@ impl Progless {
 		Msg::new(kind, format!(
 			"{} in {}.",
 			done.nice_inflect(singular.as_ref(), plural.as_ref()),
-			NiceElapsed::from(self.inner.started),
+			NiceElapsed::from(self.inner.elapsed()),
 		))
 			.with_newline(true)
 	}
 }
 
+/// # Getters.
+impl Progless {
+	#[must_use]
+	#[inline]
+	/// # Done.
+	///
+	/// Return the number of tasks completed so far.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Progless;
+	///
+	/// let pbar = Progless::try_from(1001_u32).unwrap();
+	///
+	/// // Do some stuff.
+	///
+	/// if pbar.done() == 500 {
+	///     println!("Halfway there!");
+	/// }
+	/// ```
+	pub fn done(&self) -> u32 {
+		done!(self.inner.done_total.load(SeqCst)) as u32
+	}
+
+	#[must_use]
+	#[inline]
+	/// # Elapsed Time.
+	///
+	/// Return the amount of time that has passed since the instance was first
+	/// created.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Progless;
+	/// use std::time::Duration;
+	///
+	/// let pbar = Progless::try_from(1001_u32).unwrap();
+	///
+	/// // Do some stuff.
+	///
+	/// if Duration::from_secs(60) < pbar.elapsed() {
+	///     println!("This is taking forever!");
+	/// }
+	/// ```
+	pub fn elapsed(&self) -> Duration { self.inner.elapsed() }
+
+	#[must_use]
+	#[inline]
+	/// # Snapshot.
+	///
+	/// Return a copy of the current (structured) progress state — done,
+	/// total, elapsed, title, and active tasks — for custom display or
+	/// logging purposes.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Progless;
+	///
+	/// let pbar = Progless::try_from(1001_u32).unwrap();
+	///
+	/// // Do some stuff.
+	///
+	/// let snap = pbar.snapshot();
+	/// println!("{:.1}% done.", snap.percent() * 100.0);
+	/// ```
+	pub fn snapshot(&self) -> ProglessSnapshot { self.inner.snapshot() }
+
+	#[must_use]
+	/// # Estimated Time Remaining.
+	///
+	/// Return a (very) rough estimate of the time remaining, extrapolated
+	/// from the average pace of the work done so far.
+	///
+	/// This will return `None` if no work has been completed yet, since
+	/// there's nothing to extrapolate from, and zero once everything is done.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Progless;
+	///
+	/// let pbar = Progless::try_from(1001_u32).unwrap();
+	///
+	/// // Do some stuff.
+	///
+	/// if let Some(eta) = pbar.remaining_estimate() {
+	///     println!("About {} seconds to go.", eta.as_secs());
+	/// }
+	/// ```
+	pub fn remaining_estimate(&self) -> Option<Duration> {
+		let done_total = self.inner.done_total.load(SeqCst);
+		let done = done!(done_total);
+		let total = total!(done_total);
+
+		if done == 0 { None }
+		else if total <= done { Some(Duration::ZERO) }
+		else {
+			#[expect(clippy::cast_precision_loss, reason = "It's an estimate.")]
+			let ratio = (total - done) as f64 / done as f64;
+			Some(self.elapsed().mul_f64(ratio))
+		}
+	}
+
+	#[must_use]
+	#[inline]
+	/// # Total.
+	///
+	/// Return the total number of tasks.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Progless;
+	///
+	/// let pbar = Progless::try_from(1001_u32).unwrap();
+	/// assert_eq!(pbar.total(), 1001);
+	/// ```
+	pub fn total(&self) -> u32 {
+		total!(self.inner.done_total.load(SeqCst)) as u32
+	}
+}
+
 /// # Passthrough Setters.
 impl Progless {
 	#[inline]
@@ -1393,6 +2715,38 @@ impl Progless {
 	pub fn add<S>(&self, txt: S) -> bool
 	where S: AsRef<str> { self.inner.add(txt.as_ref()) }
 
+	#[inline]
+	/// # Add a task (Thread-Tagged).
+	///
+	/// Same as [`Progless::add`], except the displayed label is prefixed with
+	/// a (dimmed) identifier for the current thread — its name, if any, or a
+	/// short hash of its ID — to help make sense of parallel workloads when
+	/// debugging.
+	///
+	/// Tasks added this way must be removed with [`Progless::remove_on_thread`]
+	/// _from the same thread_.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Progless;
+	/// # use rayon::prelude::*;
+	///
+	/// let pbar = Progless::try_from(1001_u32).unwrap();
+	/// (0..1001).into_par_iter().for_each(|i| {
+	///     let task: String = format!("Task #{}.", i);
+	///     pbar.add_on_thread(&task);
+	///
+	///     // Do some work.
+	///
+	///     pbar.remove_on_thread(&task);
+	/// });
+	///
+	/// pbar.finish();
+	/// ```
+	pub fn add_on_thread<S>(&self, txt: S) -> bool
+	where S: AsRef<str> { self.inner.add_on_thread(txt.as_ref()) }
+
 	#[inline]
 	/// # Increment Done.
 	///
@@ -1418,12 +2772,49 @@ impl Progless {
 	/// have to wait for the [`Progless`] instance to finish hogging the
 	/// display.
 	///
+	/// While the progress is on screen, messages are buffered and written
+	/// with the next tick (every hundred milliseconds or so), so rapid-fire
+	/// pushes — e.g. verbose logging from parallel jobs — are coalesced into
+	/// a single clear and repaint instead of flickering like mad. Use
+	/// [`Progless::flush_msgs`] to write them out immediately.
+	///
+	/// If a _different_ [`Progless`] instance currently has the screen, the
+	/// message is handed off to it instead, and printed with its next tick.
+	///
+	/// ## Errors
+	///
+	/// This currently always succeeds; the `Result` is kept for
+	/// compatibility. Use [`Progless::flush_msgs`] to force — and check — the
+	/// write.
+	pub fn push_msg(&self, msg: Msg) -> Result<(), Msg> {
+		self.inner.push_msg(msg);
+		Ok(())
+	}
+
+	#[inline]
+	/// # Flush Messages.
+	///
+	/// Write out any messages queued by [`Progless::push_msg`] immediately
+	/// rather than waiting for the next tick, then repaint the progress.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::{Msg, Progless};
+	///
+	/// let pbar = Progless::try_from(100_u32).unwrap();
+	/// let _res = pbar.push_msg(Msg::warning("Something happened!"));
+	///
+	/// // It is important the user see this right away.
+	/// let _res = pbar.flush_msgs();
+	/// ```
+	///
 	/// ## Errors
 	///
 	/// In practice this should never fail, but if for some reason STDERR is
-	/// tied up the original message is passed back as an error in case you
-	/// want to try to deal with it yourself.
-	pub fn push_msg(&self, msg: Msg) -> Result<(), Msg> { self.inner.push_msg(msg) }
+	/// tied up the pending messages are passed back as an error in case you
+	/// want to try to deal with them yourself.
+	pub fn flush_msgs(&self) -> Result<(), Msg> { self.inner.flush_msgs() }
 
 	#[inline]
 	/// # Remove a task.
@@ -1437,6 +2828,16 @@ impl Progless {
 	pub fn remove<S>(&self, txt: S)
 	where S: AsRef<str> { self.inner.remove(txt.as_ref()); }
 
+	#[inline]
+	/// # Remove a task (Thread-Tagged).
+	///
+	/// This is the equal and opposite companion to [`Progless::add_on_thread`],
+	/// and must be called from the same thread the task was added from.
+	///
+	/// See [`Progless::add_on_thread`] for more details.
+	pub fn remove_on_thread<S>(&self, txt: S)
+	where S: AsRef<str> { self.inner.remove_on_thread(txt.as_ref()); }
+
 	/// # Reset.
 	///
 	/// Stop the current run (if any), clear the done/doing metrics, and assign
@@ -1469,8 +2870,122 @@ impl Progless {
 	/// This method *overrides* the done value instead, so can cause
 	/// regressions if you're doing task work in parallel and one thread
 	/// finishes before another, etc.
+	///
+	/// Values at or above the total will finish the progress, unless
+	/// [`Progless::with_overshoot`] is enabled.
 	pub fn set_done(&self, done: u32) { self.inner.set_done(done); }
 
+	#[inline]
+	/// # Set Accent Color.
+	///
+	/// This is the setter companion to the [`Progless::with_accent`] builder
+	/// method. Refer to that documentation for more information.
+	pub fn set_accent(&self, color: u8) { self.inner.set_accent(color); }
+
+	#[inline]
+	/// # Set Compact Display.
+	///
+	/// This is the setter companion to the [`Progless::with_compact`] builder
+	/// method. Refer to that documentation for more information.
+	pub fn set_compact(&self, compact: bool) { self.inner.set_compact(compact); }
+
+	#[inline]
+	/// # Set Max Visible Tasks.
+	///
+	/// This is the setter companion to the [`Progless::with_max_visible_tasks`]
+	/// builder method. Refer to that documentation for more information.
+	pub fn set_max_visible_tasks(&self, max: u8) { self.inner.set_max_visible_tasks(max); }
+
+	#[inline]
+	/// # Set Overshoot.
+	///
+	/// This is the setter companion to the [`Progless::with_overshoot`]
+	/// builder method. Refer to that documentation for more information.
+	pub fn set_overshoot(&self, allow: bool) { self.inner.set_overshoot(allow); }
+
+	#[inline]
+	/// # Set Persist on Finish.
+	///
+	/// This is the setter companion to the [`Progless::with_persist_on_finish`]
+	/// builder method. Refer to that documentation for more information.
+	pub fn set_persist_on_finish(&self, persist: bool) {
+		self.inner.set_persist_on_finish(persist);
+	}
+
+	#[inline]
+	/// # Set Responsive Tasks.
+	///
+	/// This is the setter companion to the [`Progless::with_responsive_tasks`]
+	/// builder method. Refer to that documentation for more information.
+	pub fn set_responsive_tasks(&self, responsive: bool) {
+		self.inner.set_responsive_tasks(responsive);
+	}
+
+	#[inline]
+	/// # Set Smooth Bars.
+	///
+	/// This is the setter companion to the [`Progless::with_smooth_bars`]
+	/// builder method. Refer to that documentation for more information.
+	pub fn set_smooth_bars(&self, smooth: bool) { self.inner.set_smooth_bars(smooth); }
+
+	/// # Set Tasks.
+	///
+	/// Replace the entire active task list with the provided set in one go.
+	///
+	/// This is an alternative to [`Progless::add`]/[`Progless::remove`] for
+	/// cases where the complete list of "currently active" items is already
+	/// known, saving the trouble of diffing it against the previous state.
+	///
+	/// Note: unlike [`Progless::remove`], dropping tasks this way does _not_
+	/// increment the done count; use [`Progless::increment`] or
+	/// [`Progless::set_done`] for that.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use fyi_msg::Progless;
+	///
+	/// let pbar = Progless::try_from(1001_u32).unwrap();
+	///
+	/// // Do some stuff.
+	///
+	/// pbar.set_tasks(["Task #1", "Task #5", "Task #8"]);
+	/// ```
+	pub fn set_tasks<I, S>(&self, tasks: I)
+	where I: IntoIterator<Item=S>, S: AsRef<str> {
+		self.inner.set_tasks(
+			tasks.into_iter()
+				.filter_map(|t| ProglessTask::new(t.as_ref()))
+				.collect()
+		);
+	}
+
+	/// # Set Template.
+	///
+	/// This is the setter companion to the [`Progless::with_template`]
+	/// builder method. Refer to that documentation for more information.
+	///
+	/// Pass `None` to restore the default layout.
+	///
+	/// ## Errors
+	///
+	/// An error will be returned if the template is invalid, in which case
+	/// the current layout will be left as-is.
+	pub fn set_template(&self, template: Option<&str>) -> Result<(), ProglessError> {
+		let template = template.map(ProglessTemplate::try_from).transpose()?;
+		self.inner.set_template(template);
+		Ok(())
+	}
+
+	#[inline]
+	/// # Set Terminal Progress.
+	///
+	/// This is the setter companion to the [`Progless::with_terminal_progress`]
+	/// builder method. Refer to that documentation for more information.
+	pub fn set_terminal_progress(&self, enabled: bool) {
+		self.inner.set_terminal_progress(enabled);
+	}
+
 	#[inline]
 	/// # Set Title.
 	///
@@ -1544,6 +3059,18 @@ fn term_size() -> Option<(NonZeroU8, NonZeroU8)> {
 mod test {
 	use super::*;
 
+	/// # Wait to Paint.
+	///
+	/// Other tests might be painting; wait our turn.
+	fn wait_for_painter(inner: &ProglessInner) {
+		let mut tries = 0;
+		while ! inner.claim_painter() {
+			tries += 1;
+			assert!(tries < 500, "Unable to claim painter.");
+			std::thread::sleep(Duration::from_millis(10));
+		}
+	}
+
 	#[test]
 	fn t_done_total() {
 		/// # Split Done/Total.
@@ -1562,4 +3089,531 @@ mod test {
 		// Verify our mask is the right size.
 		assert_eq!(0xFFFF_FFFF_u64, u64::from(u32::MAX));
 	}
+
+	#[test]
+	fn t_drop() {
+		let (tx, rx) = std::sync::mpsc::sync_channel(16);
+		let pbar = Progless::try_from(10_u32).unwrap().with_event_sender(tx);
+		pbar.inner.flags.fetch_or(TICKING, SeqCst);
+
+		// Dropping a clone should leave things be.
+		drop(pbar.clone());
+		assert!(pbar.inner.running());
+		assert!(
+			! rx.try_iter().any(|e| matches!(e, ProglessEvent::Finished { .. })),
+			"Progress finished early.",
+		);
+
+		// Dropping the last one should shut it down.
+		drop(pbar);
+		assert!(
+			rx.try_iter().any(|e| matches!(e, ProglessEvent::Finished { .. })),
+			"Progress did not finish.",
+		);
+	}
+
+	#[test]
+	fn t_push_msg() {
+		let pbar = Progless::try_from(10_u32).unwrap();
+		pbar.inner.flags.fetch_or(TICKING, SeqCst);
+
+		// Messages are only queued if we're the ones painting.
+		wait_for_painter(&pbar.inner);
+		assert!(pbar.push_msg(Msg::plain("One")).is_ok());
+		assert!(pbar.push_msg(Msg::plain("Two")).is_ok());
+		assert_eq!(mutex!(pbar.inner.msgs).as_slice(), b"One\nTwo\n");
+
+		assert!(pbar.flush_msgs().is_ok());
+		assert!(mutex!(pbar.inner.msgs).is_empty());
+
+		// Stopping shouldn't leave anything behind either.
+		assert!(pbar.push_msg(Msg::plain("Three")).is_ok());
+		pbar.finish();
+		assert!(mutex!(pbar.inner.msgs).is_empty());
+	}
+
+	#[test]
+	fn t_push_msg_tick() {
+		let pbar = Progless::try_from(10_u32).unwrap();
+		pbar.inner.flags.fetch_or(TICKING, SeqCst);
+
+		// Queued messages should go out with the next tick whether or not
+		// there's a terminal to size up, as is often the case under test.
+		wait_for_painter(&pbar.inner);
+		assert!(pbar.push_msg(Msg::plain("Hello")).is_ok());
+		assert_eq!(mutex!(pbar.inner.msgs).as_slice(), b"Hello\n");
+		assert!(pbar.inner.tick(false));
+		assert!(mutex!(pbar.inner.msgs).is_empty());
+		pbar.finish();
+	}
+
+	#[test]
+	fn t_remaining_estimate() {
+		let pbar = Progless::try_from(10_u32).unwrap();
+
+		// Nothing to go on yet.
+		assert!(pbar.remaining_estimate().is_none());
+		assert_eq!(pbar.done(), 0);
+		assert_eq!(pbar.total(), 10);
+
+		// Half done, so the remainder should be about the same as the
+		// elapsed time.
+		std::thread::sleep(Duration::from_millis(20));
+		pbar.set_done(5);
+		assert_eq!(pbar.done(), 5);
+		let eta = pbar.remaining_estimate().expect("Missing ETA.");
+		assert!(Duration::from_millis(10) < eta, "ETA too short: {eta:?}");
+		assert!(eta <= pbar.elapsed(), "ETA too long: {eta:?}");
+
+		// All done!
+		pbar.finish();
+		assert_eq!(pbar.remaining_estimate(), Some(Duration::ZERO));
+		assert_eq!(pbar.done(), 10);
+	}
+
+	#[test]
+	fn t_clock() {
+		let inner = ProglessInner::try_from(10_u32).unwrap();
+
+		// Nothing has happened yet.
+		assert!(! inner.tick_set_secs());
+		assert_eq!(mutex!(inner.buf).elapsed.as_bytes(), b"00:00:00");
+
+		// Jump ahead a bit.
+		inner.fast_forward(Duration::from_secs(65));
+		assert!(inner.tick_set_secs());
+		assert_eq!(inner.elapsed.load(SeqCst), 65);
+		assert_eq!(mutex!(inner.buf).elapsed.as_bytes(), b"00:01:05");
+
+		// Same second, no change.
+		assert!(! inner.tick_set_secs());
+
+		// Way ahead.
+		inner.fast_forward(Duration::from_secs(3600 * 2 + 60 * 3 + 4));
+		assert!(inner.tick_set_secs());
+		assert_eq!(mutex!(inner.buf).elapsed.as_bytes(), b"02:04:09");
+
+		// Stopping should freeze the elapsed seconds.
+		inner.stop();
+		assert_eq!(inner.elapsed.load(SeqCst), 7449);
+	}
+
+	#[test]
+	fn t_clock_estimate() {
+		let pbar = Progless::try_from(10_u32).unwrap();
+		pbar.inner.fast_forward(Duration::from_secs(10));
+		pbar.set_done(5);
+
+		// Half done in ten seconds means about ten more to go.
+		let eta = pbar.remaining_estimate().expect("Missing ETA.");
+		assert!(
+			Duration::from_secs(10) <= eta && eta < Duration::from_secs(11),
+			"Unexpected ETA: {eta:?}",
+		);
+		pbar.finish();
+	}
+
+	#[test]
+	fn t_add_on_thread() {
+		let pbar = Progless::try_from(10_u32).unwrap();
+		let t_pbar = pbar.clone();
+		std::thread::Builder::new()
+			.name("worker".to_owned())
+			.spawn(move || {
+				assert!(t_pbar.add_on_thread("Task"));
+				assert_eq!(t_pbar.snapshot().tasks(), ["[worker] Task"]);
+
+				// The plain label won't match.
+				t_pbar.remove("Task");
+				assert_eq!(t_pbar.done(), 0);
+
+				t_pbar.remove_on_thread("Task");
+				assert_eq!(t_pbar.done(), 1);
+				assert!(t_pbar.snapshot().tasks().is_empty());
+			})
+			.unwrap()
+			.join()
+			.unwrap();
+
+		// Unnamed threads get a hash.
+		std::thread::spawn(|| {
+			let task = ProglessTask::new_on_thread("Task").unwrap();
+			let plain = task.plain();
+			assert!(plain.starts_with("[#"));
+			assert!(plain.ends_with("] Task"));
+		}).join().unwrap();
+
+		pbar.finish();
+	}
+
+	#[test]
+	fn t_events() {
+		let (tx, rx) = std::sync::mpsc::sync_channel(32);
+		let pbar = Progless::try_from(10_u32).unwrap().with_event_sender(tx);
+		assert!(pbar.add("\x1b[1mOne\x1b[0m"));
+		assert!(pbar.add("Two"));
+		pbar.remove("\x1b[1mOne\x1b[0m");
+		pbar.inner.tick(false);
+		pbar.finish();
+
+		// Progress events depend on tick timing, so let's look at those
+		// separately.
+		let (progress, other): (Vec<_>, Vec<_>) = rx.try_iter()
+			.partition(|e| matches!(e, ProglessEvent::Progress { .. }));
+		assert_eq!(
+			other[..4],
+			[
+				ProglessEvent::Started { total: 10 },
+				ProglessEvent::TaskAdded("One".to_owned()),
+				ProglessEvent::TaskAdded("Two".to_owned()),
+				ProglessEvent::TaskRemoved("One".to_owned()),
+			],
+		);
+		assert_eq!(other.len(), 5);
+		assert!(matches!(other[4], ProglessEvent::Finished { .. }));
+		assert_eq!(
+			progress,
+			[
+				ProglessEvent::Progress { done: 1, total: 10 },
+				ProglessEvent::Progress { done: 10, total: 10 },
+			],
+		);
+
+		// A full channel shouldn't block anything.
+		let (tx, rx) = std::sync::mpsc::sync_channel(1);
+		let pbar = Progless::try_from(10_u32).unwrap().with_event_sender(tx);
+		for i in 0..10 { pbar.add(format!("Task {i}")); }
+		pbar.finish();
+		assert_eq!(rx.try_iter().count(), 1);
+	}
+
+	#[test]
+	fn t_set_tasks() {
+		let inner = ProglessInner::try_from(10_u32).unwrap();
+		assert!(inner.add("One"));
+		assert!(inner.add("Two"));
+		inner.flags.fetch_and(! TICK_DOING, SeqCst);
+
+		// Swap them out.
+		inner.set_tasks(["Two", "Three", "", "Four"].into_iter().filter_map(ProglessTask::new).collect());
+		assert_eq!(TICK_DOING, inner.flags.load(SeqCst) & TICK_DOING);
+		assert_eq!(
+			mutex!(inner.doing).iter().map(ProglessTask::as_str).collect::<Vec<_>>(),
+			["Four", "Three", "Two"],
+		);
+
+		// The done count shouldn't have changed.
+		assert_eq!(done!(inner.done_total.load(SeqCst)), 0);
+
+		// Same again shouldn't trigger a redraw.
+		inner.flags.fetch_and(! TICK_DOING, SeqCst);
+		inner.set_tasks(["Two", "Three", "Four"].into_iter().filter_map(ProglessTask::new).collect());
+		assert_eq!(0, inner.flags.load(SeqCst) & TICK_DOING);
+	}
+
+	#[test]
+	fn t_overshoot() {
+		// By default, hitting the total stops things.
+		let inner = ProglessInner::try_from(10_u32).unwrap();
+		inner.set_done(12);
+		assert!(! inner.running());
+
+		// Unless overshooting is allowed.
+		let inner = ProglessInner::try_from(10_u32).unwrap();
+		inner.set_overshoot(true);
+		inner.set_done(12);
+		assert!(inner.running());
+		assert_eq!(done!(inner.done_total.load(SeqCst)), 10);
+
+		inner.set_done(5);
+		inner.increment_n(50);
+		assert!(inner.running());
+		assert_eq!(done!(inner.done_total.load(SeqCst)), 10);
+
+		// It should still stop when told to.
+		inner.stop();
+		assert!(! inner.running());
+	}
+
+	#[test]
+	fn t_renderer() {
+		let inner = ProglessInner::try_from(10_u32).unwrap();
+		let calls = Arc::new(Mutex::new(Vec::<(u32, u32, Vec<String>)>::new()));
+		let t_calls = Arc::clone(&calls);
+		inner.set_renderer(Some(ProglessRenderer::from(move |snap: &ProglessSnapshot| {
+			mutex!(t_calls).push((snap.done(), snap.total(), snap.tasks().to_vec()));
+		})));
+
+		// The first tick should render.
+		assert!(inner.tick(false));
+		assert_eq!(mutex!(calls).as_slice(), &[(0, 10, Vec::new())]);
+
+		// Nothing changed, so the next one shouldn't.
+		assert!(inner.tick(false));
+		assert_eq!(mutex!(calls).len(), 1);
+
+		// Now something has changed.
+		assert!(inner.add("Hello"));
+		inner.increment_n(3);
+		assert!(inner.tick(false));
+		assert_eq!(mutex!(calls).last(), Some(&(3, 10, vec!["Hello".to_owned()])));
+
+		// The snapshot should agree.
+		let snap = inner.snapshot();
+		assert_eq!(snap.done(), 3);
+		assert_eq!(snap.total(), 10);
+		assert!((snap.percent() - 0.3).abs() < 0.001);
+
+		// Stopping should render a final time.
+		inner.stop();
+		assert_eq!(mutex!(calls).last(), Some(&(10, 10, Vec::new())));
+		assert_eq!(mutex!(calls).len(), 3);
+
+		// And the renderer never touched the screen.
+		assert!(! inner.release_painter());
+	}
+
+	#[test]
+	fn t_painter() {
+		let a = ProglessInner::try_from(10_u32).unwrap();
+		let b = ProglessInner::try_from(10_u32).unwrap();
+
+		wait_for_painter(&a);
+
+		// A is painting, so B can't.
+		assert!(a.claim_painter());
+		assert!(! b.claim_painter());
+		assert!(! b.release_painter());
+
+		// Stopping A frees the screen for B.
+		a.stop();
+		assert!(! a.release_painter());
+		assert!(b.claim_painter());
+		assert!(b.release_painter());
+
+		// IDs are never reused, even if an address is.
+		assert_ne!(a.painter_id(), b.painter_id());
+		let old = a.painter_id();
+		drop(a);
+		let c = ProglessInner::try_from(10_u32).unwrap();
+		assert_ne!(c.painter_id(), old);
+	}
+
+	#[test]
+	fn t_push_msg_handoff() {
+		let a = Progless::try_from(10_u32).unwrap();
+		let b = Progless::try_from(10_u32).unwrap();
+		a.inner.flags.fetch_or(TICKING, SeqCst);
+		b.inner.flags.fetch_or(TICKING, SeqCst);
+
+		wait_for_painter(&a.inner);
+
+		// B isn't painting, so its messages should go to A.
+		assert!(b.push_msg(Msg::plain("Hello from B")).is_ok());
+		assert!(mutex!(b.inner.msgs).is_empty());
+		assert!(a.push_msg(Msg::plain("Hello from A")).is_ok());
+
+		let msgs = a.inner.take_msgs(true);
+		let msgs = String::from_utf8_lossy(&msgs);
+		assert!(msgs.starts_with("Hello from A\n"), "Missing A message: {msgs:?}");
+		assert!(msgs.contains("Hello from B\n"), "Missing B message: {msgs:?}");
+
+		// Stopping A should flush anything still pending.
+		assert!(b.push_msg(Msg::plain("Bye")).is_ok());
+		a.finish();
+		assert!(! mutex!(HANDOFF).windows(4).any(|w| w == b"Bye\n"));
+		b.finish();
+	}
+
+	#[test]
+	fn t_push_msg_no_claim() {
+		let pbar = Progless::try_from(10_u32).unwrap();
+		pbar.inner.flags.fetch_or(TICKING, SeqCst);
+
+		// Pushing a message shouldn't grab the screen; only drawing should.
+		assert!(pbar.push_msg(Msg::plain("Hello")).is_ok());
+		assert!(! pbar.inner.is_painter());
+		assert!(mutex!(pbar.inner.msgs).is_empty());
+		pbar.finish();
+	}
+
+	#[test]
+	fn t_set_doing() {
+		let width = NonZeroU8::new(60).unwrap();
+		let height = NonZeroU8::new(20).unwrap();
+		let doing: BTreeSet<ProglessTask> = (0..10_u8)
+			.filter_map(|i| ProglessTask::new(&format!("Task #{i}")))
+			.collect();
+
+		// No limit.
+		let mut buf = ProglessBuffer::DEFAULT;
+		buf.set_doing(&doing, width, height);
+		assert_eq!(buf.lines_doing, 10);
+
+		// Limited.
+		buf.max_doing = 3;
+		buf.set_doing(&doing, width, height);
+		assert_eq!(buf.lines_doing, 4);
+		assert!(buf.doing.ends_with("\x1b[2m… and 7 more".as_bytes()));
+		assert!(! buf.doing.windows(7).any(|w| w == b"Task #3"));
+
+		// A limit bigger than the list shouldn't change anything.
+		buf.max_doing = 10;
+		buf.set_doing(&doing, width, height);
+		assert_eq!(buf.lines_doing, 10);
+		assert!(! buf.doing.ends_with(b"more"));
+
+		// Not enough room for everything, but enough for a limited version.
+		let height = NonZeroU8::new(6).unwrap();
+		buf.set_doing(&doing, width, height);
+		assert_eq!(buf.lines_doing, 0);
+		buf.max_doing = 4;
+		buf.set_doing(&doing, width, height);
+		assert_eq!(buf.lines_doing, 5);
+
+		// Responsive lists fill whatever room there is.
+		buf.max_doing = 0;
+		buf.responsive = true;
+		buf.set_doing(&doing, width, height);
+		assert_eq!(buf.lines_doing, 5);
+		assert!(buf.doing.ends_with("\x1b[2m… and 6 more".as_bytes()));
+
+		let height = NonZeroU8::new(20).unwrap();
+		buf.set_doing(&doing, width, height);
+		assert_eq!(buf.lines_doing, 10);
+
+		// But still respect the cap.
+		buf.max_doing = 2;
+		buf.set_doing(&doing, width, height);
+		assert_eq!(buf.lines_doing, 3);
+
+		// Not much room at all.
+		let height = NonZeroU8::new(2).unwrap();
+		buf.set_doing(&doing, width, height);
+		assert_eq!(buf.lines_doing, 1);
+		assert!(buf.doing.ends_with("\x1b[2m… and 10 more".as_bytes()));
+
+		let height = NonZeroU8::new(1).unwrap();
+		buf.set_doing(&doing, width, height);
+		assert_eq!(buf.lines_doing, 0);
+	}
+
+	#[test]
+	fn t_set_accent() {
+		let mut buf = ProglessBuffer::DEFAULT;
+		assert_eq!(&*buf.accent_done, b"\x1b[0;1;96m");
+		assert_eq!(&*buf.accent_undone, b"\x1b[0;1;34m");
+
+		buf.set_accent(208);
+		assert_eq!(&*buf.accent_done, b"\x1b[0;1;38;5;208m");
+		assert_eq!(&*buf.accent_undone, b"\x1b[0;2;38;5;208m");
+	}
+
+	#[test]
+	fn t_set_osc() {
+		let mut buf = ProglessBuffer::DEFAULT;
+		buf.set_osc(0.0);
+		assert_eq!(buf.osc, b"\x1b]9;4;1;0\x07");
+
+		// Same percent, no change.
+		buf.osc.truncate(0);
+		buf.set_osc(0.001);
+		assert!(buf.osc.is_empty());
+
+		// Different percent.
+		buf.set_osc(0.456);
+		assert_eq!(buf.osc, b"\x1b]9;4;1;45\x07");
+
+		buf.set_osc(1.0);
+		assert_eq!(buf.osc, b"\x1b]9;4;1;100\x07");
+	}
+
+	#[test]
+	fn t_set_line() {
+		let width = NonZeroU8::new(40).unwrap();
+		let title = Msg::plain("Hello");
+		let mut buf = ProglessBuffer::DEFAULT;
+		buf.percent.replace(0.5);
+		buf.template = ProglessTemplate::try_from("{percent} [{bar}] {msg}").ok();
+
+		// The line should fill the screen exactly.
+		buf.set_line(width, 1, 2, Some(&title));
+		assert_eq!(crate::width(&buf.line), 40);
+		let plain = std::str::from_utf8(&buf.line).unwrap();
+		let plain: String = crate::iter::NoAnsi::<char, _>::new(plain.chars()).collect();
+		assert!(plain.starts_with(buf.percent.as_str()));
+		assert!(plain.ends_with("] Hello"));
+
+		// Without a title, the bar gets the extra room.
+		let before = buf.bar_done.len() + buf.bar_undone.len();
+		buf.set_line(width, 1, 2, None);
+		assert_eq!(buf.bar_done.len() + buf.bar_undone.len(), before + 5);
+
+		// The title should be omitted from its usual spot.
+		buf.set_title(Some(&title), width, width);
+		assert!(buf.title.is_empty());
+
+		// Unless the template doesn't need it.
+		buf.template = ProglessTemplate::try_from("{percent}").ok();
+		buf.set_title(Some(&title), width, width);
+		assert!(! buf.title.is_empty());
+		buf.set_line(width, 1, 2, Some(&title));
+		assert_eq!(buf.line, [b"\x1b[0;1m".as_slice(), buf.percent.as_bytes()].concat());
+
+		// Templates too wide for the screen should get chopped to fit.
+		let narrow = NonZeroU8::new(12).unwrap();
+		buf.template = ProglessTemplate::try_from(
+			"{percent} Reticulating splines, please wait… [{bar}] {msg}"
+		).ok();
+		buf.set_line(narrow, 1, 2, Some(&title));
+		assert_eq!(crate::width(&buf.line), 12);
+		let plain = std::str::from_utf8(&buf.line).unwrap();
+		let plain: String = crate::iter::NoAnsi::<char, _>::new(plain.chars()).collect();
+		assert_eq!(plain, format!("{} Retic", buf.percent.as_str()));
+
+		// Bad templates are rejected outright.
+		let pbar = Progless::try_from(10_u32).unwrap();
+		assert!(pbar.set_template(Some("{eta}")).is_err());
+		assert!(pbar.set_template(Some("{elapsed}")).is_ok());
+		assert!(pbar.set_template(None).is_ok());
+		pbar.finish();
+	}
+
+	#[test]
+	fn t_set_bars() {
+		let width = NonZeroU8::new(60).unwrap();
+		let mut buf = ProglessBuffer::DEFAULT;
+
+		// The space available to the bars.
+		let space = 60 - 19 - buf.done.len() - buf.total.len() - buf.percent.len();
+
+		// Regular bars are whole cells.
+		buf.set_bars(width, 1, 3);
+		assert_eq!(buf.bar_done.len(), space / 3);
+		assert!(buf.bar_partial.is_empty());
+		assert_eq!(buf.bar_undone.len(), space - space / 3);
+
+		// Smooth bars should split out the fractional remainder.
+		buf.smooth = true;
+		buf.set_bars(width, 1, 3);
+		let eighths = space * 8 / 3;
+		assert_eq!(buf.bar_done, "█".repeat(eighths / 8).as_bytes());
+		assert_eq!(buf.bar_partial, BAR_PARTIAL[eighths % 8]);
+		assert_eq!(
+			buf.bar_undone.len(),
+			space - eighths / 8 - usize::from(eighths % 8 != 0),
+		);
+
+		// All done.
+		buf.set_bars(width, 3, 3);
+		assert_eq!(buf.bar_done, "█".repeat(space).as_bytes());
+		assert!(buf.bar_partial.is_empty());
+		assert!(buf.bar_undone.is_empty());
+
+		// Check the partial glyphs are what we think they are.
+		assert_eq!(
+			BAR_PARTIAL.concat(),
+			"▏▎▍▌▋▊▉".as_bytes(),
+		);
+	}
 }
@@ -0,0 +1,115 @@
+/*!
+# FYI Msg - Progless Snapshots
+*/
+
+use crate::Msg;
+use std::{
+	fmt,
+	sync::Arc,
+	time::Duration,
+};
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "progress")))]
+#[derive(Debug, Clone)]
+/// # Progress Snapshot.
+///
+/// This is a point-in-time copy of the (structured) state of a
+/// [`Progless`](crate::Progless) instance, for use with custom renderers.
+///
+/// See [`Progless::with_renderer`](crate::Progless::with_renderer) and
+/// [`Progless::snapshot`](crate::Progless::snapshot) for more details.
+pub struct ProglessSnapshot {
+	/// # Done.
+	pub(super) done: u32,
+
+	/// # Total.
+	pub(super) total: u32,
+
+	/// # Elapsed.
+	pub(super) elapsed: Duration,
+
+	/// # Title.
+	pub(super) title: Option<Msg>,
+
+	/// # Active Tasks.
+	pub(super) tasks: Vec<String>,
+}
+
+impl ProglessSnapshot {
+	#[must_use]
+	#[inline]
+	/// # Done.
+	///
+	/// Return the number of tasks completed.
+	pub const fn done(&self) -> u32 { self.done }
+
+	#[must_use]
+	#[inline]
+	/// # Total.
+	///
+	/// Return the total number of tasks.
+	pub const fn total(&self) -> u32 { self.total }
+
+	#[must_use]
+	/// # Percent.
+	///
+	/// Return the completion as a fraction between `0.0..=1.0`.
+	pub fn percent(&self) -> f32 {
+		if self.done == 0 || self.total == 0 { 0.0 }
+		else if self.done >= self.total { 1.0 }
+		else {
+			#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+			let out = (f64::from(self.done) / f64::from(self.total)) as f32;
+			out
+		}
+	}
+
+	#[must_use]
+	#[inline]
+	/// # Elapsed.
+	///
+	/// Return the time elapsed.
+	pub const fn elapsed(&self) -> Duration { self.elapsed }
+
+	#[must_use]
+	#[inline]
+	/// # Title.
+	///
+	/// Return the title, if any.
+	pub const fn title(&self) -> Option<&Msg> { self.title.as_ref() }
+
+	#[must_use]
+	#[inline]
+	/// # Active Tasks.
+	///
+	/// Return the active task names, if any, sorted alphabetically.
+	pub fn tasks(&self) -> &[String] { &self.tasks }
+}
+
+
+
+#[derive(Clone)]
+/// # Custom Renderer.
+///
+/// This holds the callback set via `Progless::with_renderer`.
+pub(super) struct ProglessRenderer(Arc<dyn Fn(&ProglessSnapshot) + Send + Sync>);
+
+impl fmt::Debug for ProglessRenderer {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("ProglessRenderer")
+	}
+}
+
+impl<F> From<F> for ProglessRenderer
+where F: Fn(&ProglessSnapshot) + Send + Sync + 'static {
+	#[inline]
+	fn from(src: F) -> Self { Self(Arc::new(src)) }
+}
+
+impl ProglessRenderer {
+	#[inline]
+	/// # Render.
+	pub(super) fn render(&self, snapshot: &ProglessSnapshot) { (self.0)(snapshot); }
+}
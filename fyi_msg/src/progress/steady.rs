@@ -36,9 +36,9 @@ pub(super) struct ProglessSteady {
 
 	/// # Ticker State.
 	///
-	/// Because `ProglessInner` cannot implement `Drop`, we need an independent
-	/// state control to prevent zombie ticking in cases where the user
-	/// accidentally leaves things unfinished.
+	/// The ticker thread holds its own reference to `ProglessInner`, keeping
+	/// it alive, so we need an independent state control to prevent zombie
+	/// ticking in cases where the user accidentally leaves things unfinished.
 	state: Arc<(Mutex<bool>, Condvar)>,
 }
 
@@ -129,14 +129,14 @@ fn spawn_ticker(t_state: Arc<(Mutex<bool>, Condvar)>, t_inner: Arc<ProglessInner
 		let mut state = mutex!(t_dead);
 		while let LockResult::Ok(res) = t_cond.wait_timeout(state, ProglessSteady::TICK_RATE) {
 			state = res.0;
-			if *state { return; } // Dead!
+			if *state { break; } // Dead!
 
 			#[cfg(any(feature = "signals_sigint", feature = "signals_sigwinch"))]
 			// Dead, but from the other end.
 			if ! signals.pretick(&t_inner) || ! t_inner.tick(false) {
 				*state = true; // Update the state to match.
 				drop(state);
-				return;
+				break;
 			}
 
 			#[cfg(not(any(feature = "signals_sigint", feature = "signals_sigwinch")))]
@@ -144,8 +144,12 @@ fn spawn_ticker(t_state: Arc<(Mutex<bool>, Condvar)>, t_inner: Arc<ProglessInner
 			if ! t_inner.tick(false) {
 				*state = true; // Update the state to match.
 				drop(state);
-				return;
+				break;
 			}
 		}
+
+		// Make sure we aren't still hogging the screen, in case the instance
+		// was dropped without being finished.
+		t_inner.release_painter();
 	})
 }
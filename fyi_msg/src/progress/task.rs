@@ -8,7 +8,6 @@ use std::{
 	cmp::Ordering,
 	num::NonZeroU16,
 };
-use unicode_width::UnicodeWidthChar;
 
 
 
@@ -100,37 +99,62 @@ impl ProglessTask {
 		// If not, we have to start over and loop char-by-char.
 		out.truncate(0);
 
-		let mut width = 0_usize;
 		let mut buf = [0_u8; 4];
 		for c in NoAnsi::<char, _>::new(src.chars()) {
 			// Ignore control chars.
 			if c.is_control() {
 				// But keep whitespace (as a regular space).
-				if c.is_whitespace() {
-					width += 1;
-					out.push(b' ');
-				}
+				if c.is_whitespace() { out.push(b' '); }
 			}
 			// Keep the rest!
-			else if c.is_ascii() {
-				width += 1;
-				out.push(c as u8);
-			}
-			else {
-				width += UnicodeWidthChar::width(c).unwrap_or(1);
-				out.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
-			}
+			else { out.extend_from_slice(c.encode_utf8(&mut buf).as_bytes()); }
 		}
 
 		// Return it if we got it.
 		if out.trim_ascii().is_empty() { None }
 		else {
-			u16::try_from(width).ok()
+			// Measure by grapheme cluster.
+			u16::try_from(crate::width(&out)).ok()
 				.and_then(NonZeroU16::new)
 				.map(|w| Self::Unicode(out.into_boxed_slice(), w))
 		}
 	}
 
+	/// # New (Thread-Tagged).
+	///
+	/// Same as `new`, but with a dimmed identifier for the current thread —
+	/// its name, if any, or a short hash of its ID — prepended to the label.
+	pub(super) fn new_on_thread(src: &str) -> Option<Self> {
+		use std::hash::{
+			DefaultHasher,
+			Hash,
+			Hasher,
+		};
+
+		let task = Self::new(src)?;
+		let thread = std::thread::current();
+		let tag = thread.name().map_or_else(
+			|| {
+				let mut h = DefaultHasher::new();
+				thread.id().hash(&mut h);
+				format!("#{:04x}", h.finish() & 0xFFFF)
+			},
+			|name| name.chars().filter(|c| ! c.is_control()).collect(),
+		);
+
+		let mut out = Vec::with_capacity(tag.len() + task.as_slice().len() + 16);
+		out.extend_from_slice(b"\x1b[2m[");
+		out.extend_from_slice(tag.as_bytes());
+		out.extend_from_slice(b"]\x1b[0;35m ");
+		out.extend_from_slice(task.as_slice());
+
+		// The ANSI means length and width no longer line up, so this is
+		// always treated as Unicode.
+		u16::try_from(crate::width(&out)).ok()
+			.and_then(NonZeroU16::new)
+			.map(|w| Self::Unicode(out.into_boxed_slice(), w))
+	}
+
 	#[inline]
 	/// # As Slice.
 	///
@@ -139,6 +163,22 @@ impl ProglessTask {
 		match self { Self::Ascii(s) | Self::Unicode(s, _) => s }
 	}
 
+	#[inline]
+	/// # As Str.
+	///
+	/// Return the inner slice as a string. Note that thread-tagged tasks will
+	/// contain ANSI formatting.
+	pub(super) fn as_str(&self) -> &str {
+		std::str::from_utf8(self.as_slice()).unwrap_or_default()
+	}
+
+	/// # Plain.
+	///
+	/// Return the task as an owned string, minus any ANSI formatting.
+	pub(super) fn plain(&self) -> String {
+		NoAnsi::<char, _>::new(self.as_str().chars()).collect()
+	}
+
 	#[inline]
 	/// # Fitted.
 	///
@@ -151,24 +191,15 @@ impl ProglessTask {
 				else { Some(&s[..width]) },
 
 			// Width-based truncation will be more complicated if we need it.
-			Self::Unicode(s, w) => {
-				if width < usize::from(w.get()) {
-					let mut w = 0;
-					for (pos, c) in std::str::from_utf8(s).ok()?.char_indices() {
-						let ch_w = UnicodeWidthChar::width(c).unwrap_or(0);
-						w += ch_w;
-
-						// If this char pushed us over, we're done!
-						if width < w {
-							if pos == 0 { return None; } // Unreachable at runtime.
-							return Some(&s[..pos]);
-						}
-					}
-				}
-
-				// The string was fine as-is!
-				Some(s)
-			},
+			Self::Unicode(s, w) =>
+				// The string is fine as-is!
+				if usize::from(w.get()) <= width { Some(s) }
+				// Chop at the nearest grapheme boundary.
+				else {
+					let pos = crate::length_width(s, width);
+					if pos == 0 { None }
+					else { Some(&s[..pos]) }
+				},
 		}
 	}
 }
@@ -273,5 +304,19 @@ mod test {
 			None,
 			"Split b0 failed.",
 		);
+
+		// Grapheme clusters should never be split.
+		let c = ProglessTask::new("Hi 👨‍👩‍👧!").unwrap();
+		assert!(matches!(c, ProglessTask::Unicode(_, w) if w.get() == 6));
+		assert_eq!(
+			c.fitted(4).and_then(|s| std::str::from_utf8(s).ok()),
+			Some("Hi "),
+			"Split c4 failed.",
+		);
+		assert_eq!(
+			c.fitted(5).and_then(|s| std::str::from_utf8(s).ok()),
+			Some("Hi 👨‍👩‍👧"),
+			"Split c5 failed.",
+		);
 	}
 }
@@ -0,0 +1,205 @@
+/*!
+# FYI Msg - Progless Templates
+*/
+
+use crate::ProglessError;
+
+
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+/// # Template Token.
+pub(super) enum ProglessToken {
+	/// # Literal Text.
+	Literal(String),
+
+	/// # Elapsed Time.
+	Elapsed,
+
+	/// # Bar.
+	Bar,
+
+	/// # Done.
+	Pos,
+
+	/// # Total.
+	Len,
+
+	/// # Percent.
+	Percent,
+
+	/// # Title.
+	Msg,
+}
+
+impl ProglessToken {
+	/// # From Name.
+	///
+	/// Match a `{name}` placeholder to its token, if any.
+	fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"elapsed" => Some(Self::Elapsed),
+			"bar" => Some(Self::Bar),
+			"pos" => Some(Self::Pos),
+			"len" => Some(Self::Len),
+			"percent" => Some(Self::Percent),
+			"msg" => Some(Self::Msg),
+			_ => None,
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+/// # Progress Template.
+///
+/// This is a parsed `Progless::with_template` layout, describing the order in
+/// which the progress components — and any literal text between them — should
+/// be printed.
+pub(super) struct ProglessTemplate {
+	/// # Tokens.
+	tokens: Vec<ProglessToken>,
+
+	/// # Literal Width.
+	///
+	/// The combined display width of the literal parts.
+	literal_width: usize,
+}
+
+impl TryFrom<&str> for ProglessTemplate {
+	type Error = ProglessError;
+
+	fn try_from(src: &str) -> Result<Self, Self::Error> {
+		let mut tokens = Vec::new();
+		let mut literal = String::new();
+		let mut chars = src.chars();
+		while let Some(c) = chars.next() {
+			match c {
+				'{' => {
+					// An escaped brace.
+					let rest = chars.as_str();
+					if rest.starts_with('{') {
+						chars.next();
+						literal.push('{');
+						continue;
+					}
+
+					// A placeholder!
+					let end = rest.find('}').ok_or(ProglessError::InvalidTemplate)?;
+					let token = ProglessToken::from_name(&rest[..end])
+						.ok_or(ProglessError::InvalidTemplate)?;
+
+					// The bar and message can only appear once.
+					if
+						matches!(token, ProglessToken::Bar | ProglessToken::Msg) &&
+						tokens.contains(&token)
+					{
+						return Err(ProglessError::InvalidTemplate);
+					}
+
+					if ! literal.is_empty() {
+						tokens.push(ProglessToken::Literal(std::mem::take(&mut literal)));
+					}
+					tokens.push(token);
+					chars = rest[end + 1..].chars();
+				},
+				'}' =>
+					// Closing braces must be escaped too.
+					if chars.as_str().starts_with('}') {
+						chars.next();
+						literal.push('}');
+					}
+					else { return Err(ProglessError::InvalidTemplate); },
+				// Templates are single-line, and shouldn't contain anything
+				// that would mess with the cursor.
+				c if c.is_control() => return Err(ProglessError::InvalidTemplate),
+				c => { literal.push(c); },
+			}
+		}
+
+		if ! literal.is_empty() { tokens.push(ProglessToken::Literal(literal)); }
+		if tokens.is_empty() { return Err(ProglessError::InvalidTemplate); }
+
+		let literal_width = tokens.iter()
+			.map(|t|
+				if let ProglessToken::Literal(s) = t { crate::width(s.as_bytes()) }
+				else { 0 }
+			)
+			.sum();
+
+		Ok(Self { tokens, literal_width })
+	}
+}
+
+impl ProglessTemplate {
+	/// # Tokens.
+	pub(super) fn tokens(&self) -> &[ProglessToken] { &self.tokens }
+
+	/// # Literal Width.
+	pub(super) const fn literal_width(&self) -> usize { self.literal_width }
+
+	/// # Has Bar?
+	pub(super) fn has_bar(&self) -> bool {
+		self.tokens.contains(&ProglessToken::Bar)
+	}
+
+	/// # Has Message?
+	pub(super) fn has_msg(&self) -> bool {
+		self.tokens.contains(&ProglessToken::Msg)
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_parse() {
+		let tpl = ProglessTemplate::try_from("[{elapsed}] {bar} {pos}/{len} {{{percent}}} {msg}")
+			.expect("Template failed to parse.");
+		assert_eq!(
+			tpl.tokens(),
+			&[
+				ProglessToken::Literal("[".to_owned()),
+				ProglessToken::Elapsed,
+				ProglessToken::Literal("] ".to_owned()),
+				ProglessToken::Bar,
+				ProglessToken::Literal(" ".to_owned()),
+				ProglessToken::Pos,
+				ProglessToken::Literal("/".to_owned()),
+				ProglessToken::Len,
+				ProglessToken::Literal(" {".to_owned()),
+				ProglessToken::Percent,
+				ProglessToken::Literal("} ".to_owned()),
+				ProglessToken::Msg,
+			],
+		);
+		assert_eq!(tpl.literal_width(), 9);
+		assert!(tpl.has_bar());
+		assert!(tpl.has_msg());
+
+		let tpl = ProglessTemplate::try_from("{percent}").unwrap();
+		assert!(! tpl.has_bar());
+		assert!(! tpl.has_msg());
+
+		// Errors.
+		for bad in [
+			"",
+			"{eta}",
+			"{bar",
+			"bar}",
+			"{bar} {bar}",
+			"{msg}{msg}",
+			"{percent}\n{bar}",
+			"{ bar }",
+		] {
+			assert_eq!(
+				ProglessTemplate::try_from(bad),
+				Err(ProglessError::InvalidTemplate),
+				"Template {bad:?} should have failed.",
+			);
+		}
+	}
+}
@@ -0,0 +1,391 @@
+/*!
+# FYI Msg - Wrapping Writer
+*/
+
+use crate::fitted::{
+	length_width_from,
+	TAB_STOP,
+	width_from,
+};
+use std::io::{
+	Result,
+	Write,
+};
+
+
+
+/// # Wrapping Writer.
+///
+/// This is a streaming [`Write`] adapter that wraps its output to a given
+/// display width, incrementally, as data is written to it.
+///
+/// Lines are broken at whitespace where possible, or mid-word if a single
+/// word is too wide to fit on its own. Every line after the first — whether
+/// the break was inserted or already present in the input — is indented by
+/// the continuation indent, if any, making it easy to hang body text under a
+/// message prefix.
+///
+/// Column tracking is ANSI-aware, so formatting sequences can be written
+/// through freely without throwing off the math.
+///
+/// Only the current word is ever held in memory — and words too wide to fit
+/// on any line are written out in line-sized pieces as they arrive — so this
+/// is suitable for formatting unbounded streams. Call [`Write::flush`] — or
+/// simply drop the writer — when finished to push out the final word.
+///
+/// **This requires the `fitted` crate feature.**
+///
+/// ## Examples
+///
+/// ```
+/// use fyi_msg::MsgWrapWriter;
+/// use std::io::Write;
+///
+/// let mut out = Vec::new();
+/// {
+///     let mut writer = MsgWrapWriter::new(&mut out, 16).with_indent(6);
+///     write!(writer, "Note: The quick brown fox jumps over the lazy dog.").unwrap();
+/// }
+///
+/// assert_eq!(
+///     String::from_utf8(out).unwrap(),
+///     "Note: The quick\n      brown fox\n      jumps over\n      the lazy\n      dog.",
+/// );
+/// ```
+pub struct MsgWrapWriter<W: Write> {
+	/// # Writer.
+	writer: W,
+
+	/// # Maximum Width.
+	width: usize,
+
+	/// # Continuation Indent.
+	indent: usize,
+
+	/// # Current Column.
+	col: usize,
+
+	/// # Indent Pending?
+	///
+	/// The indent is written lazily so that blank lines don't end up with
+	/// trailing whitespace.
+	pending_indent: bool,
+
+	/// # Line Empty?
+	///
+	/// True if nothing (other than indentation) has been written to the
+	/// current line.
+	line_empty: bool,
+
+	/// # Pending Whitespace.
+	///
+	/// This is held back until the next word arrives, and dropped entirely if
+	/// that word winds up on a new line.
+	spaces: Vec<u8>,
+
+	/// # Current Word.
+	word: Vec<u8>,
+}
+
+impl<W: Write> std::fmt::Debug for MsgWrapWriter<W> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("MsgWrapWriter")
+			.field("width", &self.width)
+			.field("indent", &self.indent)
+			.field("col", &self.col)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<W: Write> Drop for MsgWrapWriter<W> {
+	#[inline]
+	fn drop(&mut self) { let _res = self.write_word(false); }
+}
+
+impl<W: Write> Write for MsgWrapWriter<W> {
+	fn write(&mut self, buf: &[u8]) -> Result<usize> {
+		for &b in buf {
+			match b {
+				b'\n' => {
+					self.write_word(false)?;
+					self.spaces.truncate(0);
+					self.writer.write_all(b"\n")?;
+					self.col = 0;
+					self.pending_indent = true;
+					self.line_empty = true;
+				},
+				b' ' | b'\t' => {
+					self.write_word(false)?;
+					self.spaces.push(b);
+				},
+				_ => {
+					// Don't let overlong words pile up. (Checking before the
+					// start of each new character ensures we aren't
+					// mid-character.)
+					if b & 0xC0 != 0x80 && self.overflows(self.word.len()) {
+						self.spill_word()?;
+					}
+
+					self.word.push(b);
+				},
+			}
+		}
+
+		Ok(buf.len())
+	}
+
+	fn flush(&mut self) -> Result<()> {
+		self.write_word(false)?;
+		self.writer.flush()
+	}
+}
+
+impl<W: Write> MsgWrapWriter<W> {
+	#[must_use]
+	/// # New.
+	///
+	/// Wrap `writer`, breaking lines that would exceed `width` columns.
+	///
+	/// A width of zero disables wrapping, though explicit line breaks will
+	/// still receive the continuation indent.
+	///
+	/// It is assumed that output begins at the start of a line.
+	pub const fn new(writer: W, width: usize) -> Self {
+		Self {
+			writer,
+			width,
+			indent: 0,
+			col: 0,
+			pending_indent: false,
+			line_empty: true,
+			spaces: Vec::new(),
+			word: Vec::new(),
+		}
+	}
+
+	#[must_use]
+	/// # With Continuation Indent.
+	///
+	/// Indent all lines after the first by this many spaces. To line things
+	/// up under a message prefix, pass the prefix's display width.
+	///
+	/// Every line needs room for at least one character, so when wrapping is
+	/// enabled, the indent is capped at `width - 1`.
+	pub const fn with_indent(mut self, indent: usize) -> Self {
+		self.indent =
+			if self.width != 0 && self.width <= indent { self.width - 1 }
+			else { indent };
+		self
+	}
+
+	#[must_use]
+	/// # Get Writer.
+	///
+	/// Return a reference to the underlying writer.
+	pub const fn get_ref(&self) -> &W { &self.writer }
+
+	#[must_use]
+	/// # Current Column.
+	///
+	/// Return the display column of the last character written, not counting
+	/// any whitespace or partial word still being held back.
+	pub const fn column(&self) -> usize { self.col }
+
+	/// # Would Overflow?
+	const fn overflows(&self, width: usize) -> bool {
+		self.width != 0 && self.width < width
+	}
+
+	/// # Break Line.
+	///
+	/// Write a line break and the continuation indent.
+	fn break_line(&mut self) -> Result<()> {
+		self.writer.write_all(b"\n")?;
+		for _ in 0..self.indent { self.writer.write_all(b" ")?; }
+		self.col = self.indent;
+		self.pending_indent = false;
+		self.line_empty = true;
+		Ok(())
+	}
+
+	/// # Spill Word.
+	///
+	/// If the pending word has grown too wide to fit on any line — i.e. it is
+	/// going to be chopped up no matter what comes next — write out all of
+	/// the pieces that fill whole lines, leaving only the remainder buffered.
+	fn spill_word(&mut self) -> Result<()> {
+		let base = if self.pending_indent { self.indent } else { self.col };
+		let start = base + width_from(&self.spaces, base, TAB_STOP);
+
+		// It still fits where it is.
+		if ! self.overflows(start + width_from(&self.word, start, TAB_STOP)) {
+			return Ok(());
+		}
+
+		// It would still fit on the next line.
+		if
+			! self.line_empty &&
+			! self.overflows(self.indent + width_from(&self.word, self.indent, TAB_STOP))
+		{
+			return Ok(());
+		}
+
+		self.write_word(true)
+	}
+
+	/// # Write Word.
+	///
+	/// Write out the pending whitespace and word, if any, breaking the line
+	/// first (or within the word) as needed.
+	///
+	/// If `partial`, the final piece of the word — the bit that doesn't need
+	/// breaking — is held back instead, so more can be added to it.
+	fn write_word(&mut self, partial: bool) -> Result<()> {
+		if self.word.is_empty() { return Ok(()); }
+		let word = std::mem::take(&mut self.word);
+
+		// Where would the word start, and would it fit there?
+		let base = if self.pending_indent { self.indent } else { self.col };
+		let start = base + width_from(&self.spaces, base, TAB_STOP);
+		let width = width_from(&word, start, TAB_STOP);
+		if ! self.line_empty && self.overflows(start + width) {
+			self.spaces.truncate(0);
+			self.break_line()?;
+		}
+		else {
+			if self.pending_indent {
+				for _ in 0..self.indent { self.writer.write_all(b" ")?; }
+				self.col = self.indent;
+				self.pending_indent = false;
+			}
+			if ! self.spaces.is_empty() {
+				self.writer.write_all(&self.spaces)?;
+				self.col = start;
+				self.spaces.truncate(0);
+			}
+		}
+
+		// Chop up words that are too big to fit on any line.
+		let mut rest = word.as_slice();
+		while self.overflows(self.col + width_from(rest, self.col, TAB_STOP)) {
+			let idx = length_width_from(rest, self.width.saturating_sub(self.col), self.col, TAB_STOP);
+			if idx == 0 { break; }
+			self.writer.write_all(&rest[..idx])?;
+			rest = &rest[idx..];
+			self.break_line()?;
+		}
+
+		// Hold the remainder back?
+		if partial {
+			let done = word.len() - rest.len();
+			self.word = word;
+			self.word.drain(..done);
+			return Ok(());
+		}
+
+		self.writer.write_all(rest)?;
+		self.col += width_from(rest, self.col, TAB_STOP);
+		self.line_empty = false;
+
+		// Recycle the allocation.
+		self.word = word;
+		self.word.truncate(0);
+		Ok(())
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// # Wrap Helper.
+	fn wrap(src: &str, width: usize, indent: usize) -> String {
+		let mut out = Vec::new();
+		{
+			let mut writer = MsgWrapWriter::new(&mut out, width).with_indent(indent);
+			// Write it one byte at a time to make sure state carries over.
+			for b in src.bytes() { writer.write_all(&[b]).unwrap(); }
+			writer.flush().unwrap();
+		}
+		String::from_utf8(out).unwrap()
+	}
+
+	#[test]
+	fn t_wrap() {
+		for &(src, width, indent, expected) in &[
+			("Hello World", 80, 0, "Hello World"),
+			("Hello World", 5, 0, "Hello\nWorld"),
+			("Hello World", 7, 2, "Hello\n  World"),
+			("Hello World", 5, 2, "Hello\n  Wor\n  ld"),
+			("Hello   World", 8, 0, "Hello\nWorld"),
+			("Hello\n\nWorld", 80, 2, "Hello\n\n  World"),
+			("Hello\n  World", 80, 2, "Hello\n    World"),
+			("Björk Guðmundsdóttir", 10, 0, "Björk\nGuðmundsdó\nttir"),
+			("\x1b[1mHello\x1b[0m World", 11, 0, "\x1b[1mHello\x1b[0m World"),
+			("\x1b[1mHello\x1b[0m World", 10, 0, "\x1b[1mHello\x1b[0m\nWorld"),
+			("Abcdefgh", 3, 1, "Abc\n de\n fg\n h"),
+			("Hello World", 0, 2, "Hello World"),
+			("Hello World", 3, 5, "Hel\n  l\n  o\n  W\n  o\n  r\n  l\n  d"),
+			("Hello World", 1, 1, "H\ne\nl\nl\no\nW\no\nr\nl\nd"),
+		] {
+			assert_eq!(
+				wrap(src, width, indent),
+				expected,
+				"Invalid wrap for {src:?} at {width}/{indent}."
+			);
+		}
+	}
+
+	#[test]
+	fn t_long_word() {
+		// Overlong words should be written out as they go rather than held
+		// until the end.
+		let mut out = Vec::new();
+		let mut writer = MsgWrapWriter::new(&mut out, 10).with_indent(2);
+		write!(writer, "Hi ").unwrap();
+		for _ in 0..1000 { writer.write_all(b"a").unwrap(); }
+		assert!(writer.word.len() <= 10, "Word buffer grew to {}.", writer.word.len());
+		writer.flush().unwrap();
+		drop(writer);
+
+		// And the pieces should still be broken correctly.
+		let mut expected = String::from("Hi");
+		for _ in 0..125 { expected.push_str("\n  aaaaaaaa"); }
+		assert_eq!(String::from_utf8(out).unwrap(), expected);
+
+		// Same for non-ASCII.
+		let mut out = Vec::new();
+		let mut writer = MsgWrapWriter::new(&mut out, 10);
+		for _ in 0..1000 { writer.write_all("é".as_bytes()).unwrap(); }
+		assert!(writer.word.len() <= 22, "Word buffer grew to {}.", writer.word.len());
+		writer.flush().unwrap();
+		drop(writer);
+
+		let out = String::from_utf8(out).unwrap();
+		assert_eq!(out.lines().count(), 100);
+		assert!(out.lines().all(|line| line == "éééééééééé"));
+	}
+
+	#[test]
+	fn t_indent() {
+		// The indent is capped to leave room for at least one character.
+		let writer = MsgWrapWriter::new(Vec::new(), 3).with_indent(5);
+		assert_eq!(writer.indent, 2);
+
+		// Unless there's no wrapping.
+		let writer = MsgWrapWriter::new(Vec::new(), 0).with_indent(5);
+		assert_eq!(writer.indent, 5);
+	}
+
+	#[test]
+	fn t_column() {
+		let mut out = Vec::new();
+		let mut writer = MsgWrapWriter::new(&mut out, 8).with_indent(2);
+		write!(writer, "Hello World").unwrap();
+		assert_eq!(writer.column(), 5); // "World" is still pending.
+		writer.flush().unwrap();
+		assert_eq!(writer.column(), 7);
+	}
+}